@@ -11,4 +11,8 @@ $CC -o words $CFLAGS words.c $LIBS
 ./words <words.list >data/word-hashes.list
 
 $CC -o blobs $CFLAGS blobs.c $LIBS
-./blobs data >data/blob-hashes.list
\ No newline at end of file
+./blobs data >data/blob-hashes.list
+
+for i in 1 2; do
+  curl -sSfo data/shattered-$i.pdf https://shattered.io/static/shattered-$i.pdf
+done
@@ -0,0 +1,524 @@
+use crate::blake2::{blake2b_long, Blake2b512Digest};
+use crate::digest::Digest;
+use crate::hmac::constant_time_eq;
+use crate::phc::{self, PhcError, PhcString};
+use core::convert::{TryFrom, TryInto};
+use core::fmt;
+use std::thread;
+
+const VERSION: u32 = 0x13;
+const BLOCK_WORDS: usize = 128;
+const SYNC_POINTS: usize = 4;
+
+pub const DEFAULT_MAX_MEMORY: usize = 1 << 31;
+pub const MAX_PASSES: u32 = 1 << 16;
+
+type Block = [u64; BLOCK_WORDS];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Variant {
+    Argon2d,
+    Argon2i,
+    Argon2id,
+}
+
+impl Variant {
+    fn id(self) -> &'static str {
+        match self {
+            Variant::Argon2d => "argon2d",
+            Variant::Argon2i => "argon2i",
+            Variant::Argon2id => "argon2id",
+        }
+    }
+
+    fn code(self) -> u32 {
+        match self {
+            Variant::Argon2d => 0,
+            Variant::Argon2i => 1,
+            Variant::Argon2id => 2,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Argon2Error {
+    InvalidMemory,
+    InvalidPasses,
+    InvalidParallelism,
+    InvalidSaltLength,
+    InvalidOutputLength,
+    MemoryLimitExceeded,
+    PassLimitExceeded,
+    Phc(PhcError),
+}
+
+impl fmt::Display for Argon2Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let message = match self {
+            Argon2Error::InvalidMemory => "m must be at least 8p kibibytes and addressable",
+            Argon2Error::InvalidPasses => "t must be positive",
+            Argon2Error::InvalidParallelism => "p must be positive and below 2^24",
+            Argon2Error::InvalidSaltLength => "salt must be at least 8 bytes long",
+            Argon2Error::InvalidOutputLength => "output must be at least 4 bytes long",
+            Argon2Error::MemoryLimitExceeded => "parameters require more memory than allowed",
+            Argon2Error::PassLimitExceeded => "t is above the limit for decoded parameters",
+            Argon2Error::Phc(error) => return error.fmt(f),
+        };
+        f.write_str(message)
+    }
+}
+
+impl std::error::Error for Argon2Error {}
+
+impl From<PhcError> for Argon2Error {
+    fn from(error: PhcError) -> Self {
+        Argon2Error::Phc(error)
+    }
+}
+
+fn mix(v: &mut Block, a: usize, b: usize, c: usize, d: usize) {
+    fn mul(x: u64, y: u64) -> u64 {
+        2u64.wrapping_mul(x & 0xffffffff)
+            .wrapping_mul(y & 0xffffffff)
+    }
+
+    v[a] = v[a].wrapping_add(v[b]).wrapping_add(mul(v[a], v[b]));
+    v[d] = (v[d] ^ v[a]).rotate_right(32);
+    v[c] = v[c].wrapping_add(v[d]).wrapping_add(mul(v[c], v[d]));
+    v[b] = (v[b] ^ v[c]).rotate_right(24);
+    v[a] = v[a].wrapping_add(v[b]).wrapping_add(mul(v[a], v[b]));
+    v[d] = (v[d] ^ v[a]).rotate_right(16);
+    v[c] = v[c].wrapping_add(v[d]).wrapping_add(mul(v[c], v[d]));
+    v[b] = (v[b] ^ v[c]).rotate_right(63);
+}
+
+fn permute(v: &mut Block, indices: [usize; 16]) {
+    for &(a, b, c, d) in [
+        (0, 4, 8, 12),
+        (1, 5, 9, 13),
+        (2, 6, 10, 14),
+        (3, 7, 11, 15),
+        (0, 5, 10, 15),
+        (1, 6, 11, 12),
+        (2, 7, 8, 13),
+        (3, 4, 9, 14),
+    ]
+    .iter()
+    {
+        mix(v, indices[a], indices[b], indices[c], indices[d]);
+    }
+}
+
+fn compress(x: &Block, y: &Block) -> Block {
+    let mut r = [0u64; BLOCK_WORDS];
+    for (word, (x, y)) in r.iter_mut().zip(x.iter().zip(y.iter())) {
+        *word = x ^ y;
+    }
+
+    let mut z = r;
+    for row in 0..8 {
+        permute(&mut z, core::array::from_fn(|i| 16 * row + i));
+    }
+    for column in 0..8 {
+        permute(
+            &mut z,
+            core::array::from_fn(|i| 16 * (i / 2) + 2 * column + i % 2),
+        );
+    }
+
+    for (word, r) in z.iter_mut().zip(r.iter()) {
+        *word ^= r;
+    }
+    z
+}
+
+struct Memory<'a> {
+    before: &'a [Block],
+    after: &'a [Block],
+    lanes: usize,
+    segment_length: usize,
+    slice: usize,
+}
+
+impl<'a> Memory<'a> {
+    fn block<'b>(&'b self, segment: &'b [Block], lane: usize, column: usize) -> &'b Block {
+        let slice = column / self.segment_length;
+        let offset = column % self.segment_length;
+
+        if slice == self.slice {
+            &segment[offset]
+        } else {
+            let index = (slice * self.lanes + lane) * self.segment_length + offset;
+            if slice < self.slice {
+                &self.before[index]
+            } else {
+                &self.after[index - (self.slice + 1) * self.lanes * self.segment_length]
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Argon2 {
+    variant: Variant,
+    m: u32,
+    t: u32,
+    p: u32,
+    threads: usize,
+    secret: Vec<u8>,
+    associated_data: Vec<u8>,
+}
+
+impl Argon2 {
+    pub fn new(variant: Variant, m: u32, t: u32, p: u32) -> Result<Self, Argon2Error> {
+        if p == 0 || p >= 1 << 24 {
+            return Err(Argon2Error::InvalidParallelism);
+        }
+        if t == 0 {
+            return Err(Argon2Error::InvalidPasses);
+        }
+        if (m as u64) < 8 * p as u64 {
+            return Err(Argon2Error::InvalidMemory);
+        }
+        if usize::try_from(1024 * m as u64).is_err() {
+            return Err(Argon2Error::InvalidMemory);
+        }
+
+        Ok(Self {
+            variant,
+            m,
+            t,
+            p,
+            threads: 1,
+            secret: Vec::new(),
+            associated_data: Vec::new(),
+        })
+    }
+
+    pub fn threads(mut self, threads: usize) -> Self {
+        assert!(threads > 0);
+        self.threads = threads;
+        self
+    }
+
+    pub fn secret(mut self, secret: &[u8]) -> Self {
+        self.secret = secret.to_vec();
+        self
+    }
+
+    pub fn associated_data(mut self, associated_data: &[u8]) -> Self {
+        self.associated_data = associated_data.to_vec();
+        self
+    }
+
+    pub fn variant(&self) -> Variant {
+        self.variant
+    }
+
+    pub fn m(&self) -> u32 {
+        self.m
+    }
+
+    pub fn t(&self) -> u32 {
+        self.t
+    }
+
+    pub fn p(&self) -> u32 {
+        self.p
+    }
+
+    pub fn memory(&self) -> usize {
+        let lanes = SYNC_POINTS * self.p as usize;
+        1024 * (self.m as usize / lanes * lanes)
+    }
+
+    fn initial_hash(&self, password: &[u8], salt: &[u8], tag_length: usize) -> [u8; 64] {
+        let mut digest = Blake2b512Digest::new();
+        for value in [
+            self.p,
+            u32::try_from(tag_length).unwrap(),
+            self.m,
+            self.t,
+            VERSION,
+            self.variant.code(),
+        ] {
+            digest.update(value.to_le_bytes());
+        }
+        for input in [password, salt, &self.secret, &self.associated_data] {
+            digest.update(u32::try_from(input.len()).unwrap().to_le_bytes());
+            digest.update(input);
+        }
+        digest.finalize()
+    }
+
+    fn fill_segment(&self, memory: &Memory, segment: &mut [Block], pass: u32, lane: usize) {
+        let slice = memory.slice;
+        let lanes = memory.lanes;
+        let segment_length = memory.segment_length;
+        let lane_length = SYNC_POINTS * segment_length;
+
+        let data_independent = self.variant == Variant::Argon2i
+            || (self.variant == Variant::Argon2id && pass == 0 && slice < SYNC_POINTS / 2);
+
+        let zero = [0u64; BLOCK_WORDS];
+        let mut input = [0u64; BLOCK_WORDS];
+        let mut addresses = [0u64; BLOCK_WORDS];
+        input[0..6].copy_from_slice(&[
+            pass as u64,
+            lane as u64,
+            slice as u64,
+            (lanes * lane_length) as u64,
+            self.t as u64,
+            self.variant.code() as u64,
+        ]);
+
+        let next_addresses = |input: &mut Block| {
+            input[6] += 1;
+            compress(&zero, &compress(&zero, input))
+        };
+
+        let start = if pass == 0 && slice == 0 { 2 } else { 0 };
+        if data_independent && start == 2 {
+            addresses = next_addresses(&mut input);
+        }
+
+        for index in start..segment_length {
+            let column = slice * segment_length + index;
+            let previous_column = if column == 0 {
+                lane_length - 1
+            } else {
+                column - 1
+            };
+
+            let random = if data_independent {
+                if index % BLOCK_WORDS == 0 {
+                    addresses = next_addresses(&mut input);
+                }
+                addresses[index % BLOCK_WORDS]
+            } else {
+                memory.block(segment, lane, previous_column)[0]
+            };
+
+            let reference_lane = if pass == 0 && slice == 0 {
+                lane
+            } else {
+                (random >> 32) as usize % lanes
+            };
+
+            let completed = if pass == 0 {
+                slice * segment_length
+            } else {
+                lane_length - segment_length
+            };
+            let area = if reference_lane == lane {
+                completed + index - 1
+            } else if index == 0 {
+                completed - 1
+            } else {
+                completed
+            };
+
+            let j1 = random & 0xffffffff;
+            let x = (j1 * j1) >> 32;
+            let y = (area as u64 * x) >> 32;
+            let relative = area - 1 - y as usize;
+
+            let start_column = if pass == 0 || slice == SYNC_POINTS - 1 {
+                0
+            } else {
+                (slice + 1) * segment_length
+            };
+            let reference_column = (start_column + relative) % lane_length;
+
+            let block = compress(
+                memory.block(segment, lane, previous_column),
+                memory.block(segment, reference_lane, reference_column),
+            );
+
+            if pass == 0 {
+                segment[index] = block;
+            } else {
+                for (word, block) in segment[index].iter_mut().zip(block.iter()) {
+                    *word ^= block;
+                }
+            }
+        }
+    }
+
+    pub fn derive(
+        &self,
+        password: &[u8],
+        salt: &[u8],
+        output: &mut [u8],
+    ) -> Result<(), Argon2Error> {
+        if salt.len() < 8 {
+            return Err(Argon2Error::InvalidSaltLength);
+        }
+        if output.len() < 4 {
+            return Err(Argon2Error::InvalidOutputLength);
+        }
+
+        let lanes = self.p as usize;
+        let segment_length = self.m as usize / (SYNC_POINTS * lanes);
+        let lane_length = SYNC_POINTS * segment_length;
+        let mut blocks = vec![[0u64; BLOCK_WORDS]; lanes * lane_length];
+
+        let initial_hash = self.initial_hash(password, salt, output.len());
+        let mut bytes = [0u8; 8 * BLOCK_WORDS];
+        for lane in 0..lanes {
+            for column in 0..2 {
+                blake2b_long(
+                    &[
+                        &initial_hash,
+                        &(column as u32).to_le_bytes(),
+                        &(lane as u32).to_le_bytes(),
+                    ],
+                    &mut bytes,
+                );
+                for (word, bytes) in blocks[lane * segment_length + column]
+                    .iter_mut()
+                    .zip(bytes.chunks(8))
+                {
+                    *word = u64::from_le_bytes(bytes.try_into().unwrap());
+                }
+            }
+        }
+
+        for pass in 0..self.t {
+            for slice in 0..SYNC_POINTS {
+                let (before, rest) = blocks.split_at_mut(slice * lanes * segment_length);
+                let (current, after) = rest.split_at_mut(lanes * segment_length);
+                let memory = Memory {
+                    before,
+                    after,
+                    lanes,
+                    segment_length,
+                    slice,
+                };
+
+                if self.threads == 1 || lanes < 2 {
+                    for (lane, segment) in current.chunks_mut(segment_length).enumerate() {
+                        self.fill_segment(&memory, segment, pass, lane);
+                    }
+                } else {
+                    let per_thread = lanes.div_ceil(self.threads);
+                    let memory = &memory;
+
+                    thread::scope(|scope| {
+                        for (i, segments) in
+                            current.chunks_mut(per_thread * segment_length).enumerate()
+                        {
+                            scope.spawn(move || {
+                                for (j, segment) in segments.chunks_mut(segment_length).enumerate()
+                                {
+                                    self.fill_segment(memory, segment, pass, i * per_thread + j);
+                                }
+                            });
+                        }
+                    });
+                }
+            }
+        }
+
+        let mut last = [0u64; BLOCK_WORDS];
+        for lane in 0..lanes {
+            let block = &blocks[((SYNC_POINTS - 1) * lanes + lane + 1) * segment_length - 1];
+            for (word, block) in last.iter_mut().zip(block.iter()) {
+                *word ^= block;
+            }
+        }
+        for (bytes, word) in bytes.chunks_mut(8).zip(last.iter()) {
+            bytes.copy_from_slice(&word.to_le_bytes());
+        }
+
+        blake2b_long(&[&bytes], output);
+        Ok(())
+    }
+
+    pub fn encode(&self, password: &[u8], salt: &[u8], len: usize) -> Result<String, Argon2Error> {
+        let mut hash = vec![0u8; len];
+        self.derive(password, salt, &mut hash)?;
+
+        let mut params = vec![
+            ("m".to_owned(), self.m.to_string()),
+            ("t".to_owned(), self.t.to_string()),
+            ("p".to_owned(), self.p.to_string()),
+        ];
+        if !self.associated_data.is_empty() {
+            params.push(("data".to_owned(), phc::base64(&self.associated_data)));
+        }
+
+        Ok(PhcString {
+            id: self.variant.id().to_owned(),
+            version: Some(VERSION),
+            params,
+            salt: salt.to_vec(),
+            hash,
+        }
+        .to_string())
+    }
+
+    pub fn decode(encoded: &str) -> Result<(Self, PhcString), Argon2Error> {
+        Self::decode_with_max_memory(encoded, DEFAULT_MAX_MEMORY)
+    }
+
+    pub fn decode_with_max_memory(
+        encoded: &str,
+        max_memory: usize,
+    ) -> Result<(Self, PhcString), Argon2Error> {
+        let phc = PhcString::parse(encoded)?;
+        let variant = [Variant::Argon2d, Variant::Argon2i, Variant::Argon2id]
+            .iter()
+            .copied()
+            .find(|variant| variant.id() == phc.id)
+            .ok_or(PhcError::UnsupportedAlgorithm)?;
+
+        if phc.version != Some(VERSION) {
+            return Err(PhcError::InvalidParameter.into());
+        }
+
+        let mut argon2 = Self::new(
+            variant,
+            phc.param_u32("m")?,
+            phc.param_u32("t")?,
+            phc.param_u32("p")?,
+        )?;
+        if argon2.memory() > max_memory {
+            return Err(Argon2Error::MemoryLimitExceeded);
+        }
+        if argon2.t > MAX_PASSES {
+            return Err(Argon2Error::PassLimitExceeded);
+        }
+        if let Some(data) = phc.param("data") {
+            argon2.associated_data = phc::decode_base64(data)?;
+        }
+
+        Ok((argon2, phc))
+    }
+
+    pub fn verify(password: &[u8], encoded: &str) -> Result<bool, Argon2Error> {
+        Self::verify_with_secret(password, &[], encoded)
+    }
+
+    pub fn verify_with_secret(
+        password: &[u8],
+        secret: &[u8],
+        encoded: &str,
+    ) -> Result<bool, Argon2Error> {
+        Self::verify_with_max_memory(password, secret, encoded, DEFAULT_MAX_MEMORY)
+    }
+
+    pub fn verify_with_max_memory(
+        password: &[u8],
+        secret: &[u8],
+        encoded: &str,
+        max_memory: usize,
+    ) -> Result<bool, Argon2Error> {
+        let (argon2, phc) = Self::decode_with_max_memory(encoded, max_memory)?;
+        let mut hash = vec![0u8; phc.hash.len()];
+        argon2
+            .secret(secret)
+            .derive(password, &phc.salt, &mut hash)?;
+        Ok(constant_time_eq(&hash, &phc.hash))
+    }
+}
@@ -0,0 +1,220 @@
+use crate::digest::{Digest, Xof, XofReader};
+use core::mem::replace;
+
+const RATE: usize = 8;
+
+const ROUND_CONSTANTS: [u64; 16] = [
+    0x3c, 0x2d, 0x1e, 0x0f, 0xf0, 0xe1, 0xd2, 0xc3, 0xb4, 0xa5, 0x96, 0x87, 0x78, 0x69, 0x5a, 0x4b,
+];
+
+const HASH256_IV: u64 = 0x0000080100cc0002;
+const XOF128_IV: u64 = 0x0000080000cc0003;
+const CXOF128_IV: u64 = 0x0000080000cc0004;
+
+pub const CUSTOMIZATION_MAX_LEN: usize = 256;
+
+pub fn ascon_p(state: &mut [u64; 5], rounds: usize) {
+    for &constant in ROUND_CONSTANTS[16 - rounds..].iter() {
+        let [mut x0, mut x1, mut x2, mut x3, mut x4] = *state;
+
+        x2 ^= constant;
+
+        x0 ^= x4;
+        x4 ^= x3;
+        x2 ^= x1;
+        let t0 = !x0 & x1;
+        let t1 = !x1 & x2;
+        let t2 = !x2 & x3;
+        let t3 = !x3 & x4;
+        let t4 = !x4 & x0;
+        x0 ^= t1;
+        x1 ^= t2;
+        x2 ^= t3;
+        x3 ^= t4;
+        x4 ^= t0;
+        x1 ^= x0;
+        x0 ^= x4;
+        x3 ^= x2;
+        x2 = !x2;
+
+        *state = [
+            x0 ^ x0.rotate_right(19) ^ x0.rotate_right(28),
+            x1 ^ x1.rotate_right(61) ^ x1.rotate_right(39),
+            x2 ^ x2.rotate_right(1) ^ x2.rotate_right(6),
+            x3 ^ x3.rotate_right(10) ^ x3.rotate_right(17),
+            x4 ^ x4.rotate_right(7) ^ x4.rotate_right(41),
+        ];
+    }
+}
+
+#[derive(Clone)]
+pub struct AsconSponge {
+    state: [u64; 5],
+    offset: usize,
+}
+
+impl AsconSponge {
+    pub fn new(iv: u64) -> Self {
+        let mut state = [iv, 0, 0, 0, 0];
+        ascon_p(&mut state, 12);
+        Self { state, offset: 0 }
+    }
+
+    pub fn absorb(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.state[0] ^= (byte as u64) << (8 * self.offset);
+            self.offset += 1;
+            if self.offset == RATE {
+                ascon_p(&mut self.state, 12);
+                self.offset = 0;
+            }
+        }
+    }
+
+    pub fn pad(&mut self) {
+        self.state[0] ^= 0x01 << (8 * self.offset);
+        ascon_p(&mut self.state, 12);
+        self.offset = 0;
+    }
+
+    pub fn squeeze(&mut self, bytes: &mut [u8]) {
+        for byte in bytes.iter_mut() {
+            if self.offset == RATE {
+                ascon_p(&mut self.state, 12);
+                self.offset = 0;
+            }
+            *byte = (self.state[0] >> (8 * self.offset)) as u8;
+            self.offset += 1;
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct AsconHash256Digest {
+    sponge: AsconSponge,
+}
+
+impl Digest<[u8; 32]> for AsconHash256Digest {
+    const BLOCK_SIZE: usize = RATE;
+    const OUTPUT_SIZE: usize = 32;
+
+    fn new() -> Self {
+        Self {
+            sponge: AsconSponge::new(HASH256_IV),
+        }
+    }
+
+    fn hash(bytes: impl AsRef<[u8]>) -> [u8; 32] {
+        Self::new().chain(bytes).finalize()
+    }
+
+    fn update(&mut self, bytes: impl AsRef<[u8]>) {
+        self.sponge.absorb(bytes.as_ref());
+    }
+
+    fn chain(mut self, bytes: impl AsRef<[u8]>) -> Self {
+        self.update(bytes);
+        self
+    }
+
+    fn finalize(mut self) -> [u8; 32] {
+        self.finalize_reset()
+    }
+
+    fn finalize_reset(&mut self) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        AsconReader::new(replace(&mut self.sponge, AsconSponge::new(HASH256_IV))).read(&mut bytes);
+        bytes
+    }
+}
+
+#[derive(Clone)]
+pub struct AsconXof128 {
+    sponge: AsconSponge,
+}
+
+impl Xof<AsconReader> for AsconXof128 {
+    fn new() -> Self {
+        Self {
+            sponge: AsconSponge::new(XOF128_IV),
+        }
+    }
+
+    fn hash(bytes: impl AsRef<[u8]>) -> AsconReader {
+        Self::new().chain(bytes).finalize()
+    }
+
+    fn update(&mut self, bytes: impl AsRef<[u8]>) {
+        self.sponge.absorb(bytes.as_ref());
+    }
+
+    fn chain(mut self, bytes: impl AsRef<[u8]>) -> Self {
+        self.update(bytes);
+        self
+    }
+
+    fn finalize(self) -> AsconReader {
+        AsconReader::new(self.sponge)
+    }
+
+    fn finalize_reset(&mut self) -> AsconReader {
+        AsconReader::new(replace(&mut self.sponge, AsconSponge::new(XOF128_IV)))
+    }
+}
+
+#[derive(Clone)]
+pub struct AsconCXof128 {
+    sponge: AsconSponge,
+    initial: AsconSponge,
+}
+
+impl AsconCXof128 {
+    pub fn new(customization: &[u8]) -> Self {
+        assert!(customization.len() <= CUSTOMIZATION_MAX_LEN);
+
+        let mut sponge = AsconSponge::new(CXOF128_IV);
+        sponge.absorb(&(8 * customization.len() as u64).to_le_bytes());
+        sponge.absorb(customization);
+        sponge.pad();
+
+        Self {
+            sponge: sponge.clone(),
+            initial: sponge,
+        }
+    }
+
+    pub fn update(&mut self, bytes: impl AsRef<[u8]>) {
+        self.sponge.absorb(bytes.as_ref());
+    }
+
+    pub fn chain(mut self, bytes: impl AsRef<[u8]>) -> Self {
+        self.update(bytes);
+        self
+    }
+
+    pub fn finalize(self) -> AsconReader {
+        AsconReader::new(self.sponge)
+    }
+
+    pub fn finalize_reset(&mut self) -> AsconReader {
+        AsconReader::new(replace(&mut self.sponge, self.initial.clone()))
+    }
+}
+
+#[derive(Clone)]
+pub struct AsconReader {
+    sponge: AsconSponge,
+}
+
+impl AsconReader {
+    pub fn new(mut sponge: AsconSponge) -> Self {
+        sponge.pad();
+        Self { sponge }
+    }
+}
+
+impl XofReader for AsconReader {
+    fn read(&mut self, bytes: &mut [u8]) {
+        self.sponge.squeeze(bytes);
+    }
+}
@@ -0,0 +1,397 @@
+use crate::digest::Digest;
+use core::convert::{TryFrom, TryInto};
+
+const SIGMA: [[usize; 16]; 12] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [14, 10, 4, 8, 9, 15, 13, 6, 1, 12, 0, 2, 11, 7, 5, 3],
+    [11, 8, 12, 0, 5, 2, 15, 13, 10, 14, 3, 6, 7, 1, 9, 4],
+    [7, 9, 3, 1, 13, 12, 11, 14, 2, 6, 5, 10, 4, 0, 15, 8],
+    [9, 0, 5, 7, 2, 4, 10, 15, 14, 1, 11, 12, 6, 8, 3, 13],
+    [2, 12, 6, 10, 0, 11, 8, 3, 4, 13, 7, 5, 15, 14, 1, 9],
+    [12, 5, 1, 15, 14, 13, 4, 10, 0, 7, 6, 3, 9, 2, 8, 11],
+    [13, 11, 7, 14, 12, 1, 3, 9, 5, 0, 15, 4, 8, 6, 2, 10],
+    [6, 15, 14, 9, 11, 3, 0, 8, 12, 2, 13, 7, 1, 4, 10, 5],
+    [10, 2, 8, 4, 7, 6, 1, 5, 15, 11, 9, 14, 3, 12, 13, 0],
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [14, 10, 4, 8, 9, 15, 13, 6, 1, 12, 0, 2, 11, 7, 5, 3],
+];
+
+macro_rules! blake2_impl {
+    (
+        $params:ident,
+        $name:ident,
+        $word:ty,
+        $counter:ty,
+        $block_bytes:literal,
+        $rounds:literal,
+        $node_offset_bytes:literal,
+        ($r1:literal, $r2:literal, $r3:literal, $r4:literal),
+        $iv:expr
+    ) => {
+        #[derive(Clone)]
+        pub struct $params {
+            key: [u8; $block_bytes / 2],
+            key_length: usize,
+            salt: [u8; $block_bytes / 8],
+            personal: [u8; $block_bytes / 8],
+            fanout: u8,
+            depth: u8,
+            leaf_length: u32,
+            node_offset: u64,
+            node_depth: u8,
+            inner_length: u8,
+            last_node: bool,
+        }
+
+        impl $params {
+            pub fn new() -> Self {
+                Self {
+                    key: [0u8; $block_bytes / 2],
+                    key_length: 0,
+                    salt: [0u8; $block_bytes / 8],
+                    personal: [0u8; $block_bytes / 8],
+                    fanout: 1,
+                    depth: 1,
+                    leaf_length: 0,
+                    node_offset: 0,
+                    node_depth: 0,
+                    inner_length: 0,
+                    last_node: false,
+                }
+            }
+
+            pub fn key(mut self, key: &[u8]) -> Self {
+                assert!(key.len() <= $block_bytes / 2);
+                self.key = [0u8; $block_bytes / 2];
+                self.key[0..key.len()].copy_from_slice(key);
+                self.key_length = key.len();
+                self
+            }
+
+            pub fn salt(mut self, salt: &[u8]) -> Self {
+                assert!(salt.len() <= $block_bytes / 8);
+                self.salt = [0u8; $block_bytes / 8];
+                self.salt[0..salt.len()].copy_from_slice(salt);
+                self
+            }
+
+            pub fn personal(mut self, personal: &[u8]) -> Self {
+                assert!(personal.len() <= $block_bytes / 8);
+                self.personal = [0u8; $block_bytes / 8];
+                self.personal[0..personal.len()].copy_from_slice(personal);
+                self
+            }
+
+            pub fn fanout(mut self, fanout: u8) -> Self {
+                self.fanout = fanout;
+                self
+            }
+
+            pub fn depth(mut self, depth: u8) -> Self {
+                assert!(depth != 0);
+                self.depth = depth;
+                self
+            }
+
+            pub fn leaf_length(mut self, leaf_length: u32) -> Self {
+                self.leaf_length = leaf_length;
+                self
+            }
+
+            pub fn node_offset(mut self, node_offset: u64) -> Self {
+                assert!(node_offset >> (8 * $node_offset_bytes - 1) >> 1 == 0);
+                self.node_offset = node_offset;
+                self
+            }
+
+            pub fn node_depth(mut self, node_depth: u8) -> Self {
+                self.node_depth = node_depth;
+                self
+            }
+
+            pub fn inner_length(mut self, inner_length: u8) -> Self {
+                assert!(inner_length as usize <= $block_bytes / 2);
+                self.inner_length = inner_length;
+                self
+            }
+
+            pub fn last_node(mut self, last_node: bool) -> Self {
+                self.last_node = last_node;
+                self
+            }
+
+            fn initial_state(&self, digest_length: usize) -> [$word; 8] {
+                const WORD_BYTES: usize = core::mem::size_of::<$word>();
+                const SALT_OFFSET: usize = 4 * WORD_BYTES;
+                const PERSONAL_OFFSET: usize = 6 * WORD_BYTES;
+
+                let mut block = [0u8; 8 * WORD_BYTES];
+                block[0] = digest_length as u8;
+                block[1] = self.key_length as u8;
+                block[2] = self.fanout;
+                block[3] = self.depth;
+                block[4..8].copy_from_slice(&self.leaf_length.to_le_bytes());
+                block[8..8 + $node_offset_bytes]
+                    .copy_from_slice(&self.node_offset.to_le_bytes()[0..$node_offset_bytes]);
+                block[8 + $node_offset_bytes] = self.node_depth;
+                block[9 + $node_offset_bytes] = self.inner_length;
+                block[SALT_OFFSET..SALT_OFFSET + $block_bytes / 8].copy_from_slice(&self.salt);
+                block[PERSONAL_OFFSET..PERSONAL_OFFSET + $block_bytes / 8]
+                    .copy_from_slice(&self.personal);
+
+                let mut state: [$word; 8] = $iv;
+                for (h, word) in state.iter_mut().zip(block.chunks(WORD_BYTES)) {
+                    *h ^= <$word>::from_le_bytes(word.try_into().unwrap());
+                }
+                state
+            }
+        }
+
+        impl Default for $params {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        #[derive(Clone)]
+        pub struct $name<const LEN: usize> {
+            params: $params,
+            digest_length: usize,
+            state: [$word; 8],
+            counter: $counter,
+            block: [u8; $block_bytes],
+            offset: usize,
+        }
+
+        impl<const LEN: usize> $name<LEN> {
+            pub fn with_params(params: &$params) -> Self {
+                Self::with_digest_length(params, LEN)
+            }
+
+            pub fn with_digest_length(params: &$params, digest_length: usize) -> Self {
+                assert!((1..=$block_bytes / 2).contains(&LEN));
+                assert!((1..=LEN).contains(&digest_length));
+
+                let mut digest = Self {
+                    params: params.clone(),
+                    digest_length,
+                    state: [0; 8],
+                    counter: 0,
+                    block: [0u8; $block_bytes],
+                    offset: 0,
+                };
+                digest.reset();
+                digest
+            }
+
+            pub fn new_keyed(key: &[u8]) -> Self {
+                Self::with_params(&$params::new().key(key))
+            }
+
+            pub fn finalize_variable(mut self, output: &mut [u8]) {
+                self.finalize_variable_reset(output);
+            }
+
+            pub fn finalize_variable_reset(&mut self, output: &mut [u8]) {
+                assert_eq!(output.len(), self.digest_length);
+
+                self.counter = self.counter.wrapping_add(self.offset as $counter);
+                self.block[self.offset..].fill(0);
+                self.compress(true);
+
+                for (chunk, word) in output
+                    .chunks_mut(core::mem::size_of::<$word>())
+                    .zip(self.state.iter())
+                {
+                    chunk.copy_from_slice(&word.to_le_bytes()[0..chunk.len()]);
+                }
+
+                self.reset();
+            }
+
+            fn reset(&mut self) {
+                self.state = self.params.initial_state(self.digest_length);
+                self.counter = 0;
+                self.block = [0u8; $block_bytes];
+                self.offset = 0;
+
+                if self.params.key_length > 0 {
+                    self.block[0..$block_bytes / 2].copy_from_slice(&self.params.key);
+                    self.offset = $block_bytes;
+                }
+            }
+
+            #[allow(clippy::many_single_char_names)]
+            fn compress(&mut self, last: bool) {
+                const WORD_BYTES: usize = core::mem::size_of::<$word>();
+                const IV: [$word; 8] = $iv;
+
+                fn g(
+                    v: &mut [$word; 16],
+                    a: usize,
+                    b: usize,
+                    c: usize,
+                    d: usize,
+                    x: $word,
+                    y: $word,
+                ) {
+                    v[a] = v[a].wrapping_add(v[b]).wrapping_add(x);
+                    v[d] = (v[d] ^ v[a]).rotate_right($r1);
+                    v[c] = v[c].wrapping_add(v[d]);
+                    v[b] = (v[b] ^ v[c]).rotate_right($r2);
+                    v[a] = v[a].wrapping_add(v[b]).wrapping_add(y);
+                    v[d] = (v[d] ^ v[a]).rotate_right($r3);
+                    v[c] = v[c].wrapping_add(v[d]);
+                    v[b] = (v[b] ^ v[c]).rotate_right($r4);
+                }
+
+                let mut m = [0 as $word; 16];
+                for (word, bytes) in m.iter_mut().zip(self.block.chunks(WORD_BYTES)) {
+                    *word = <$word>::from_le_bytes(bytes.try_into().unwrap());
+                }
+
+                let mut v = [0 as $word; 16];
+                v[0..8].copy_from_slice(&self.state);
+                v[8..16].copy_from_slice(&IV);
+                v[12] ^= self.counter as $word;
+                v[13] ^= (self.counter >> (8 * WORD_BYTES)) as $word;
+                if last {
+                    v[14] = !v[14];
+                    if self.params.last_node {
+                        v[15] = !v[15];
+                    }
+                }
+
+                for s in SIGMA.iter().take($rounds) {
+                    g(&mut v, 0, 4, 8, 12, m[s[0]], m[s[1]]);
+                    g(&mut v, 1, 5, 9, 13, m[s[2]], m[s[3]]);
+                    g(&mut v, 2, 6, 10, 14, m[s[4]], m[s[5]]);
+                    g(&mut v, 3, 7, 11, 15, m[s[6]], m[s[7]]);
+                    g(&mut v, 0, 5, 10, 15, m[s[8]], m[s[9]]);
+                    g(&mut v, 1, 6, 11, 12, m[s[10]], m[s[11]]);
+                    g(&mut v, 2, 7, 8, 13, m[s[12]], m[s[13]]);
+                    g(&mut v, 3, 4, 9, 14, m[s[14]], m[s[15]]);
+                }
+
+                for (i, h) in self.state.iter_mut().enumerate() {
+                    *h ^= v[i] ^ v[i + 8];
+                }
+            }
+        }
+
+        impl<const LEN: usize> Digest<[u8; LEN]> for $name<LEN> {
+            const BLOCK_SIZE: usize = $block_bytes;
+            const OUTPUT_SIZE: usize = LEN;
+
+            fn new() -> Self {
+                Self::with_params(&$params::new())
+            }
+
+            fn hash(bytes: impl AsRef<[u8]>) -> [u8; LEN] {
+                Self::new().chain(bytes).finalize()
+            }
+
+            fn update(&mut self, bytes: impl AsRef<[u8]>) {
+                let mut bytes = bytes.as_ref();
+
+                while !bytes.is_empty() {
+                    if self.offset == $block_bytes {
+                        self.counter = self.counter.wrapping_add($block_bytes);
+                        self.compress(false);
+                        self.offset = 0;
+                    }
+
+                    let len = ($block_bytes - self.offset).min(bytes.len());
+                    self.block[self.offset..self.offset + len].copy_from_slice(&bytes[0..len]);
+                    self.offset += len;
+                    bytes = &bytes[len..];
+                }
+            }
+
+            fn chain(mut self, bytes: impl AsRef<[u8]>) -> Self {
+                self.update(bytes);
+                self
+            }
+
+            fn finalize(mut self) -> [u8; LEN] {
+                self.finalize_reset()
+            }
+
+            fn finalize_reset(&mut self) -> [u8; LEN] {
+                let mut bytes = [0u8; LEN];
+                let digest_length = self.digest_length;
+                self.finalize_variable_reset(&mut bytes[0..digest_length]);
+                bytes
+            }
+        }
+    };
+}
+
+blake2_impl!(
+    Blake2bParams,
+    Blake2b,
+    u64,
+    u128,
+    128,
+    12,
+    8,
+    (32, 24, 16, 63),
+    [
+        0x6a09e667f3bcc908,
+        0xbb67ae8584caa73b,
+        0x3c6ef372fe94f82b,
+        0xa54ff53a5f1d36f1,
+        0x510e527fade682d1,
+        0x9b05688c2b3e6c1f,
+        0x1f83d9abfb41bd6b,
+        0x5be0cd19137e2179,
+    ]
+);
+
+blake2_impl!(
+    Blake2sParams,
+    Blake2s,
+    u32,
+    u64,
+    64,
+    10,
+    6,
+    (16, 12, 8, 7),
+    [
+        0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab,
+        0x5be0cd19,
+    ]
+);
+
+pub type Blake2b512Digest = Blake2b<64>;
+pub type Blake2s256Digest = Blake2s<32>;
+
+fn blake2b_variable(inputs: &[&[u8]], output: &mut [u8]) {
+    let mut digest = Blake2b512Digest::with_digest_length(&Blake2bParams::new(), output.len());
+    for input in inputs {
+        digest.update(input);
+    }
+    digest.finalize_variable(output);
+}
+
+pub fn blake2b_long(inputs: &[&[u8]], output: &mut [u8]) {
+    let len = u32::try_from(output.len()).unwrap().to_le_bytes();
+    let mut prefixed = vec![&len[..]];
+    prefixed.extend_from_slice(inputs);
+
+    if output.len() <= 64 {
+        blake2b_variable(&prefixed, output);
+        return;
+    }
+
+    let mut v = [0u8; 64];
+    blake2b_variable(&prefixed, &mut v);
+
+    let r = output.len().div_ceil(32) - 2;
+    output[0..32].copy_from_slice(&v[0..32]);
+    for i in 1..r {
+        v = Blake2b512Digest::hash(v);
+        output[32 * i..32 * i + 32].copy_from_slice(&v[0..32]);
+    }
+    blake2b_variable(&[&v], &mut output[32 * r..]);
+}
@@ -0,0 +1,415 @@
+use crate::digest::{Digest, XofReader};
+use core::convert::TryInto;
+use std::thread;
+
+const IV: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+const MESSAGE_PERMUTATION: [usize; 16] = [2, 6, 3, 10, 7, 0, 4, 13, 1, 11, 12, 5, 9, 14, 15, 8];
+
+const BLOCK_LEN: usize = 64;
+const CHUNK_LEN: usize = 1024;
+
+const CHUNK_START: u32 = 1 << 0;
+const CHUNK_END: u32 = 1 << 1;
+const PARENT: u32 = 1 << 2;
+const ROOT: u32 = 1 << 3;
+const KEYED_HASH: u32 = 1 << 4;
+const DERIVE_KEY_CONTEXT: u32 = 1 << 5;
+const DERIVE_KEY_MATERIAL: u32 = 1 << 6;
+
+type ChainingValue = [u32; 8];
+
+fn compress(
+    chaining_value: &ChainingValue,
+    block: &[u8; BLOCK_LEN],
+    counter: u64,
+    block_len: usize,
+    flags: u32,
+) -> [u32; 16] {
+    fn g(v: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize, x: u32, y: u32) {
+        v[a] = v[a].wrapping_add(v[b]).wrapping_add(x);
+        v[d] = (v[d] ^ v[a]).rotate_right(16);
+        v[c] = v[c].wrapping_add(v[d]);
+        v[b] = (v[b] ^ v[c]).rotate_right(12);
+        v[a] = v[a].wrapping_add(v[b]).wrapping_add(y);
+        v[d] = (v[d] ^ v[a]).rotate_right(8);
+        v[c] = v[c].wrapping_add(v[d]);
+        v[b] = (v[b] ^ v[c]).rotate_right(7);
+    }
+
+    let mut m = [0u32; 16];
+    for (word, bytes) in m.iter_mut().zip(block.chunks(4)) {
+        *word = u32::from_le_bytes(bytes.try_into().unwrap());
+    }
+
+    let mut v = [0u32; 16];
+    v[0..8].copy_from_slice(chaining_value);
+    v[8..12].copy_from_slice(&IV[0..4]);
+    v[12] = counter as u32;
+    v[13] = (counter >> 32) as u32;
+    v[14] = block_len as u32;
+    v[15] = flags;
+
+    for round in 0..7 {
+        g(&mut v, 0, 4, 8, 12, m[0], m[1]);
+        g(&mut v, 1, 5, 9, 13, m[2], m[3]);
+        g(&mut v, 2, 6, 10, 14, m[4], m[5]);
+        g(&mut v, 3, 7, 11, 15, m[6], m[7]);
+        g(&mut v, 0, 5, 10, 15, m[8], m[9]);
+        g(&mut v, 1, 6, 11, 12, m[10], m[11]);
+        g(&mut v, 2, 7, 8, 13, m[12], m[13]);
+        g(&mut v, 3, 4, 9, 14, m[14], m[15]);
+
+        if round != 6 {
+            let permuted = m;
+            for (word, &index) in m.iter_mut().zip(MESSAGE_PERMUTATION.iter()) {
+                *word = permuted[index];
+            }
+        }
+    }
+
+    for i in 0..8 {
+        v[i] ^= v[i + 8];
+        v[i + 8] ^= chaining_value[i];
+    }
+    v
+}
+
+fn words_from_bytes(bytes: &[u8; 32]) -> ChainingValue {
+    let mut words = [0u32; 8];
+    for (word, bytes) in words.iter_mut().zip(bytes.chunks(4)) {
+        *word = u32::from_le_bytes(bytes.try_into().unwrap());
+    }
+    words
+}
+
+#[derive(Clone)]
+struct Output {
+    chaining_value: ChainingValue,
+    block: [u8; BLOCK_LEN],
+    counter: u64,
+    block_len: usize,
+    flags: u32,
+}
+
+impl Output {
+    fn parent(
+        left: &ChainingValue,
+        right: &ChainingValue,
+        key: &ChainingValue,
+        flags: u32,
+    ) -> Self {
+        let mut block = [0u8; BLOCK_LEN];
+        for (bytes, word) in block.chunks_mut(4).zip(left.iter().chain(right.iter())) {
+            bytes.copy_from_slice(&word.to_le_bytes());
+        }
+
+        Self {
+            chaining_value: *key,
+            block,
+            counter: 0,
+            block_len: BLOCK_LEN,
+            flags: flags | PARENT,
+        }
+    }
+
+    fn chaining_value(&self) -> ChainingValue {
+        let words = compress(
+            &self.chaining_value,
+            &self.block,
+            self.counter,
+            self.block_len,
+            self.flags,
+        );
+        words[0..8].try_into().unwrap()
+    }
+
+    fn root_block(&self, counter: u64) -> [u8; BLOCK_LEN] {
+        let words = compress(
+            &self.chaining_value,
+            &self.block,
+            counter,
+            self.block_len,
+            self.flags | ROOT,
+        );
+
+        let mut bytes = [0u8; BLOCK_LEN];
+        for (chunk, word) in bytes.chunks_mut(4).zip(words.iter()) {
+            chunk.copy_from_slice(&word.to_le_bytes());
+        }
+        bytes
+    }
+}
+
+#[derive(Clone)]
+struct ChunkState {
+    chaining_value: ChainingValue,
+    counter: u64,
+    block: [u8; BLOCK_LEN],
+    block_len: usize,
+    blocks_compressed: usize,
+    flags: u32,
+}
+
+impl ChunkState {
+    fn new(key: &ChainingValue, counter: u64, flags: u32) -> Self {
+        Self {
+            chaining_value: *key,
+            counter,
+            block: [0u8; BLOCK_LEN],
+            block_len: 0,
+            blocks_compressed: 0,
+            flags,
+        }
+    }
+
+    fn len(&self) -> usize {
+        BLOCK_LEN * self.blocks_compressed + self.block_len
+    }
+
+    fn start_flag(&self) -> u32 {
+        if self.blocks_compressed == 0 {
+            CHUNK_START
+        } else {
+            0
+        }
+    }
+
+    fn update(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+
+        while !bytes.is_empty() {
+            if self.block_len == BLOCK_LEN {
+                let words = compress(
+                    &self.chaining_value,
+                    &self.block,
+                    self.counter,
+                    BLOCK_LEN,
+                    self.flags | self.start_flag(),
+                );
+                self.chaining_value = words[0..8].try_into().unwrap();
+                self.blocks_compressed += 1;
+                self.block = [0u8; BLOCK_LEN];
+                self.block_len = 0;
+            }
+
+            let len = (BLOCK_LEN - self.block_len).min(bytes.len());
+            self.block[self.block_len..self.block_len + len].copy_from_slice(&bytes[0..len]);
+            self.block_len += len;
+            bytes = &bytes[len..];
+        }
+    }
+
+    fn output(&self) -> Output {
+        Output {
+            chaining_value: self.chaining_value,
+            block: self.block,
+            counter: self.counter,
+            block_len: self.block_len,
+            flags: self.flags | self.start_flag() | CHUNK_END,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Blake3Digest {
+    key: ChainingValue,
+    flags: u32,
+    chunk: ChunkState,
+    stack: Vec<ChainingValue>,
+    threads: usize,
+}
+
+impl Blake3Digest {
+    fn with_key_and_flags(key: ChainingValue, flags: u32) -> Self {
+        Self {
+            key,
+            flags,
+            chunk: ChunkState::new(&key, 0, flags),
+            stack: Vec::new(),
+            threads: 1,
+        }
+    }
+
+    pub fn new_keyed(key: &[u8; 32]) -> Self {
+        Self::with_key_and_flags(words_from_bytes(key), KEYED_HASH)
+    }
+
+    pub fn new_derive_key(context: &str) -> Self {
+        let context_key = Self::with_key_and_flags(IV, DERIVE_KEY_CONTEXT)
+            .chain(context)
+            .finalize();
+        Self::with_key_and_flags(words_from_bytes(&context_key), DERIVE_KEY_MATERIAL)
+    }
+
+    pub fn keyed_hash(key: &[u8; 32], bytes: impl AsRef<[u8]>) -> [u8; 32] {
+        Self::new_keyed(key).chain(bytes).finalize()
+    }
+
+    pub fn derive_key(context: &str, key_material: impl AsRef<[u8]>) -> [u8; 32] {
+        Self::new_derive_key(context).chain(key_material).finalize()
+    }
+
+    pub fn threads(mut self, threads: usize) -> Self {
+        assert!(threads > 0);
+        self.threads = threads;
+        self
+    }
+
+    pub fn finalize_xof(mut self) -> Blake3Reader {
+        self.finalize_xof_reset()
+    }
+
+    pub fn finalize_xof_reset(&mut self) -> Blake3Reader {
+        self.merge_stack(self.chunk.counter);
+
+        let mut output = self.chunk.output();
+        for chaining_value in self.stack.iter().rev() {
+            output = Output::parent(
+                chaining_value,
+                &output.chaining_value(),
+                &self.key,
+                self.flags,
+            );
+        }
+
+        self.chunk = ChunkState::new(&self.key, 0, self.flags);
+        self.stack.clear();
+
+        Blake3Reader {
+            output,
+            block: [0u8; BLOCK_LEN],
+            position: 0,
+        }
+    }
+
+    fn push_chaining_value(&mut self, chaining_value: ChainingValue, counter: u64) {
+        self.merge_stack(counter);
+        self.stack.push(chaining_value);
+    }
+
+    fn merge_stack(&mut self, counter: u64) {
+        while self.stack.len() > counter.count_ones() as usize {
+            let right = self.stack.pop().unwrap();
+            let left = self.stack.pop().unwrap();
+            let parent = Output::parent(&left, &right, &self.key, self.flags);
+            self.stack.push(parent.chaining_value());
+        }
+    }
+
+    fn subtree_chaining_value(&self, bytes: &[u8], counter: u64, threads: usize) -> ChainingValue {
+        if bytes.len() == CHUNK_LEN {
+            let mut chunk = ChunkState::new(&self.key, counter, self.flags);
+            chunk.update(bytes);
+            return chunk.output().chaining_value();
+        }
+
+        let half = bytes.len() / 2;
+        let right_counter = counter + (half / CHUNK_LEN) as u64;
+
+        let (left, right) = if threads > 1 {
+            thread::scope(|scope| {
+                let left = scope
+                    .spawn(|| self.subtree_chaining_value(&bytes[0..half], counter, threads / 2));
+                let right = self.subtree_chaining_value(
+                    &bytes[half..],
+                    right_counter,
+                    threads - threads / 2,
+                );
+                (left.join().unwrap(), right)
+            })
+        } else {
+            (
+                self.subtree_chaining_value(&bytes[0..half], counter, 1),
+                self.subtree_chaining_value(&bytes[half..], right_counter, 1),
+            )
+        };
+
+        Output::parent(&left, &right, &self.key, self.flags).chaining_value()
+    }
+}
+
+impl Digest<[u8; 32]> for Blake3Digest {
+    const BLOCK_SIZE: usize = BLOCK_LEN;
+    const OUTPUT_SIZE: usize = 32;
+
+    fn new() -> Self {
+        Self::with_key_and_flags(IV, 0)
+    }
+
+    fn hash(bytes: impl AsRef<[u8]>) -> [u8; 32] {
+        Self::new().chain(bytes).finalize()
+    }
+
+    fn update(&mut self, bytes: impl AsRef<[u8]>) {
+        let mut bytes = bytes.as_ref();
+
+        while !bytes.is_empty() {
+            if self.chunk.len() == CHUNK_LEN {
+                let chaining_value = self.chunk.output().chaining_value();
+                let counter = self.chunk.counter;
+                self.push_chaining_value(chaining_value, counter);
+                self.chunk = ChunkState::new(&self.key, counter + 1, self.flags);
+            }
+
+            if self.chunk.len() == 0 && bytes.len() > CHUNK_LEN {
+                // Consume the largest aligned power-of-two run of whole chunks that still leaves
+                // input behind, so that the root node is always formed in finalize.
+                let counter = self.chunk.counter;
+                let mut chunks = 1u64 << (63 - ((bytes.len() - 1) / CHUNK_LEN).leading_zeros());
+                while !counter.is_multiple_of(chunks) {
+                    chunks /= 2;
+                }
+
+                let len = chunks as usize * CHUNK_LEN;
+                let chaining_value =
+                    self.subtree_chaining_value(&bytes[0..len], counter, self.threads);
+                self.push_chaining_value(chaining_value, counter);
+                self.chunk = ChunkState::new(&self.key, counter + chunks, self.flags);
+                bytes = &bytes[len..];
+                continue;
+            }
+
+            let len = (CHUNK_LEN - self.chunk.len()).min(bytes.len());
+            self.chunk.update(&bytes[0..len]);
+            bytes = &bytes[len..];
+        }
+    }
+
+    fn chain(mut self, bytes: impl AsRef<[u8]>) -> Self {
+        self.update(bytes);
+        self
+    }
+
+    fn finalize(mut self) -> [u8; 32] {
+        self.finalize_reset()
+    }
+
+    fn finalize_reset(&mut self) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        self.finalize_xof_reset().read(&mut bytes);
+        bytes
+    }
+}
+
+#[derive(Clone)]
+pub struct Blake3Reader {
+    output: Output,
+    block: [u8; BLOCK_LEN],
+    position: u64,
+}
+
+impl XofReader for Blake3Reader {
+    fn read(&mut self, bytes: &mut [u8]) {
+        for byte in bytes.iter_mut() {
+            let offset = (self.position % BLOCK_LEN as u64) as usize;
+            if offset == 0 {
+                self.block = self.output.root_block(self.position / BLOCK_LEN as u64);
+            }
+            *byte = self.block[offset];
+            self.position += 1;
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use crate::digest::Digest;
+
+const POLYNOMIAL: u32 = 0x82f63b78;
+
+const TABLE: [u32; 256] = table();
+
+const fn table() -> [u32; 256] {
+    let mut table = [0u32; 256];
+    let mut byte = 0;
+    while byte < 256 {
+        let mut crc = byte as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ POLYNOMIAL
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[byte] = crc;
+        byte += 1;
+    }
+    table
+}
+
+#[derive(Clone)]
+pub struct CRC32CDigest {
+    seed: u32,
+    crc: u32,
+}
+
+impl CRC32CDigest {
+    pub fn with_seed(seed: u32) -> Self {
+        Self { seed, crc: !seed }
+    }
+}
+
+impl Digest<[u8; 4]> for CRC32CDigest {
+    const BLOCK_SIZE: usize = 4;
+    const OUTPUT_SIZE: usize = 4;
+
+    fn new() -> Self {
+        Self::with_seed(0)
+    }
+
+    fn hash(bytes: impl AsRef<[u8]>) -> [u8; 4] {
+        Self::new().chain(bytes).finalize()
+    }
+
+    fn update(&mut self, bytes: impl AsRef<[u8]>) {
+        for &byte in bytes.as_ref() {
+            self.crc = (self.crc >> 8) ^ TABLE[(self.crc as u8 ^ byte) as usize];
+        }
+    }
+
+    fn chain(mut self, bytes: impl AsRef<[u8]>) -> Self {
+        self.update(bytes);
+        self
+    }
+
+    fn finalize(mut self) -> [u8; 4] {
+        self.finalize_reset()
+    }
+
+    fn finalize_reset(&mut self) -> [u8; 4] {
+        let crc = !self.crc;
+        self.crc = !self.seed;
+        crc.to_be_bytes()
+    }
+}
@@ -0,0 +1,97 @@
+use crate::sponge::{Sponge, SpongeReader};
+use core::mem::replace;
+
+pub struct Encoding {
+    bytes: [u8; 9],
+    len: usize,
+}
+
+impl AsRef<[u8]> for Encoding {
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes[0..self.len]
+    }
+}
+
+fn encoded_len(value: u64) -> usize {
+    (64 - value.leading_zeros() as usize).div_ceil(8).max(1)
+}
+
+pub fn left_encode(value: u64) -> Encoding {
+    let n = encoded_len(value);
+    let mut bytes = [0u8; 9];
+    bytes[0] = n as u8;
+    bytes[1..n + 1].copy_from_slice(&value.to_be_bytes()[8 - n..]);
+    Encoding { bytes, len: n + 1 }
+}
+
+pub fn right_encode(value: u64) -> Encoding {
+    let n = encoded_len(value);
+    let mut bytes = [0u8; 9];
+    bytes[0..n].copy_from_slice(&value.to_be_bytes()[8 - n..]);
+    bytes[n] = n as u8;
+    Encoding { bytes, len: n + 1 }
+}
+
+pub fn absorb_encoded_string<const RATE: usize>(sponge: &mut Sponge<RATE>, bytes: &[u8]) {
+    sponge.absorb(left_encode(8 * bytes.len() as u64).as_ref());
+    sponge.absorb(bytes);
+}
+
+pub fn absorb_bytepad<const RATE: usize>(sponge: &mut Sponge<RATE>, strings: &[&[u8]]) {
+    sponge.absorb(left_encode(RATE as u64).as_ref());
+    for bytes in strings.iter() {
+        absorb_encoded_string(sponge, bytes);
+    }
+    sponge.fill_block();
+}
+
+#[derive(Clone)]
+pub struct CShake<const RATE: usize> {
+    sponge: Sponge<RATE>,
+    initial: Sponge<RATE>,
+    domain: u8,
+}
+
+impl<const RATE: usize> CShake<RATE> {
+    pub fn new(function_name: &[u8], customization: &[u8]) -> Self {
+        let mut sponge = Sponge::new();
+
+        let domain = if function_name.is_empty() && customization.is_empty() {
+            0x1f
+        } else {
+            absorb_bytepad(&mut sponge, &[function_name, customization]);
+            0x04
+        };
+
+        Self {
+            sponge: sponge.clone(),
+            initial: sponge,
+            domain,
+        }
+    }
+
+    pub fn update(&mut self, bytes: impl AsRef<[u8]>) {
+        self.sponge.absorb(bytes.as_ref());
+    }
+
+    pub fn chain(mut self, bytes: impl AsRef<[u8]>) -> Self {
+        self.update(bytes);
+        self
+    }
+
+    pub fn finalize(self) -> SpongeReader<RATE> {
+        SpongeReader::new(self.sponge, self.domain)
+    }
+
+    pub fn finalize_reset(&mut self) -> SpongeReader<RATE> {
+        let sponge = replace(&mut self.sponge, self.initial.clone());
+        SpongeReader::new(sponge, self.domain)
+    }
+
+    pub fn sponge_mut(&mut self) -> &mut Sponge<RATE> {
+        &mut self.sponge
+    }
+}
+
+pub type CSHAKE128Xof = CShake<168>;
+pub type CSHAKE256Xof = CShake<136>;
@@ -1,9 +1,15 @@
 use crate::padding::{
-    pad_bytes_1024, pad_bytes_512, BlockConsumer1024, BlockConsumer512, Padder1024, Padder512,
+    pad_bytes_1024, pad_bytes_512, pad_bytes_512_wide, pad_final_block_1024, pad_final_block_512,
+    pad_final_block_512_wide, BigEndian, BlockConsumer1024, BlockConsumer512, Counter128,
+    Counter256, Counter64, LengthPadding, Padder1024, Padder512, Padder512Wide,
 };
+use core::convert::TryInto;
 use core::marker::PhantomData;
 
 pub trait Digest<Res> {
+    const BLOCK_SIZE: usize;
+    const OUTPUT_SIZE: usize;
+
     fn new() -> Self;
     fn hash(bytes: impl AsRef<[u8]>) -> Res;
     fn update(&mut self, bytes: impl AsRef<[u8]>);
@@ -12,7 +18,36 @@ pub trait Digest<Res> {
     fn finalize_reset(&mut self) -> Res;
 }
 
+pub trait BitDigest<Res>: Digest<Res> {
+    fn hash_bits(bytes: impl AsRef<[u8]>, bit_len: usize) -> Res;
+    fn update_bits(&mut self, bytes: impl AsRef<[u8]>, bit_len: usize);
+    fn chain_bits(self, bytes: impl AsRef<[u8]>, bit_len: usize) -> Self;
+    fn finalize_bits(self, bytes: impl AsRef<[u8]>, bit_len: usize) -> Res;
+}
+
+pub trait MidstateDigest<Res>: Digest<Res> {
+    type Midstate: Clone;
+
+    fn midstate(block: &[u8]) -> Self::Midstate;
+    fn finalize_midstate(midstate: &Self::Midstate, bytes: &[u8]) -> Res;
+}
+
+pub trait Xof<Reader: XofReader> {
+    fn new() -> Self;
+    fn hash(bytes: impl AsRef<[u8]>) -> Reader;
+    fn update(&mut self, bytes: impl AsRef<[u8]>);
+    fn chain(self, bytes: impl AsRef<[u8]>) -> Self;
+    fn finalize(self) -> Reader;
+    fn finalize_reset(&mut self) -> Reader;
+}
+
+pub trait XofReader {
+    fn read(&mut self, bytes: &mut [u8]);
+}
+
 pub trait HashState<Res, ScheduleTuple>: Clone {
+    const OUTPUT_SIZE: usize = core::mem::size_of::<Res>();
+
     fn new() -> Self;
     fn step(&self, tuple: ScheduleTuple) -> Self;
     fn merge(&self, other: &Self) -> Self;
@@ -50,6 +85,18 @@ macro_rules! hash_block_consumer_impl {
             }
         }
 
+        impl<Res, Schedule: $schedule_trait, State: HashState<Res, Schedule::Item>> Clone
+            for $name<Res, Schedule, State>
+        {
+            fn clone(&self) -> Self {
+                Self {
+                    state: self.state.clone(),
+                    result: PhantomData,
+                    schedule: PhantomData,
+                }
+            }
+        }
+
         impl<Res, Schedule: $schedule_trait, State: HashState<Res, Schedule::Item>>
             $block_consumer_trait<Res> for $name<Res, Schedule, State>
         {
@@ -76,14 +123,49 @@ hash_block_consumer_impl!(HashBlockConsumer512, BlockConsumer512, Schedule512, 6
 hash_block_consumer_impl!(HashBlockConsumer1024, BlockConsumer1024, Schedule1024, 128);
 
 macro_rules! digest_impl {
-    ($name:ident, $schedule_trait:ident, $padder_type:ident, $consumer_type:ident, $pad_bytes_fn:ident) => {
-        pub struct $name<Res, Schedule: $schedule_trait, State: HashState<Res, Schedule::Item>> {
-            padder: $padder_type<Res, $consumer_type<Res, Schedule, State>>,
+    (
+        $name:ident,
+        $block_bytes:literal,
+        $counter_type:ty,
+        $schedule_trait:ident,
+        $padder_type:ident,
+        $consumer_type:ident,
+        $pad_final_block_fn:ident,
+        $pad_bytes_fn:ident
+    ) => {
+        pub struct $name<
+            Res,
+            Schedule: $schedule_trait,
+            State: HashState<Res, Schedule::Item>,
+            P: LengthPadding = BigEndian,
+        > {
+            padder: $padder_type<Res, P, $consumer_type<Res, Schedule, State>>,
         }
 
-        impl<Res, Schedule: $schedule_trait, State: HashState<Res, Schedule::Item>> Digest<Res>
-            for $name<Res, Schedule, State>
+        impl<
+                Res,
+                Schedule: $schedule_trait,
+                State: HashState<Res, Schedule::Item>,
+                P: LengthPadding,
+            > Clone for $name<Res, Schedule, State, P>
         {
+            fn clone(&self) -> Self {
+                Self {
+                    padder: self.padder.clone(),
+                }
+            }
+        }
+
+        impl<
+                Res,
+                Schedule: $schedule_trait,
+                State: HashState<Res, Schedule::Item>,
+                P: LengthPadding,
+            > Digest<Res> for $name<Res, Schedule, State, P>
+        {
+            const BLOCK_SIZE: usize = $block_bytes;
+            const OUTPUT_SIZE: usize = State::OUTPUT_SIZE;
+
             fn new() -> Self {
                 Self {
                     padder: $padder_type::new($consumer_type::<Res, Schedule, State>::new()),
@@ -92,7 +174,7 @@ macro_rules! digest_impl {
 
             fn hash(bytes: impl AsRef<[u8]>) -> Res {
                 let mut consumer = $consumer_type::<Res, Schedule, State>::new();
-                $pad_bytes_fn(&mut consumer, bytes.as_ref());
+                $pad_bytes_fn::<Res, P, _>(&mut consumer, bytes.as_ref());
                 consumer.state.to_bytes()
             }
 
@@ -114,20 +196,90 @@ macro_rules! digest_impl {
                 self.padder.finalize_reset()
             }
         }
+
+        impl<
+                Res,
+                Schedule: $schedule_trait,
+                State: HashState<Res, Schedule::Item>,
+                P: LengthPadding,
+            > MidstateDigest<Res> for $name<Res, Schedule, State, P>
+        {
+            type Midstate = State;
+
+            fn midstate(block: &[u8]) -> State {
+                let mut consumer = $consumer_type::<Res, Schedule, State>::new();
+                consumer.handle(block.try_into().unwrap());
+                consumer.state
+            }
+
+            fn finalize_midstate(midstate: &State, bytes: &[u8]) -> Res {
+                let mut consumer = $consumer_type::<Res, Schedule, State>::new();
+                consumer.state = midstate.clone();
+
+                let tail = bytes.len() - bytes.len() % $block_bytes;
+                for block in bytes[0..tail].chunks($block_bytes) {
+                    consumer.handle(block.try_into().unwrap());
+                }
+                $pad_final_block_fn::<Res, P, _>(
+                    &mut consumer,
+                    &bytes[tail..],
+                    <$counter_type>::from($block_bytes + bytes.len()) * 8,
+                );
+                consumer.state.to_bytes()
+            }
+        }
+
+        impl<Res, Schedule: $schedule_trait, State: HashState<Res, Schedule::Item>> BitDigest<Res>
+            for $name<Res, Schedule, State, BigEndian>
+        {
+            fn hash_bits(bytes: impl AsRef<[u8]>, bit_len: usize) -> Res {
+                Self::new().finalize_bits(bytes, bit_len)
+            }
+
+            fn update_bits(&mut self, bytes: impl AsRef<[u8]>, bit_len: usize) {
+                self.padder.feed_bits(bytes.as_ref(), bit_len);
+            }
+
+            fn chain_bits(mut self, bytes: impl AsRef<[u8]>, bit_len: usize) -> Self {
+                self.update_bits(bytes, bit_len);
+                self
+            }
+
+            fn finalize_bits(mut self, bytes: impl AsRef<[u8]>, bit_len: usize) -> Res {
+                self.update_bits(bytes, bit_len);
+                self.finalize()
+            }
+        }
     };
 }
 
 digest_impl!(
     Digest512,
+    64,
+    Counter64,
     Schedule512,
     Padder512,
     HashBlockConsumer512,
+    pad_final_block_512,
     pad_bytes_512
 );
 digest_impl!(
     Digest1024,
+    128,
+    Counter128,
     Schedule1024,
     Padder1024,
     HashBlockConsumer1024,
+    pad_final_block_1024,
     pad_bytes_1024
 );
+digest_impl!(
+    Digest512Wide,
+    64,
+    Counter256,
+    Schedule512,
+    Padder512Wide,
+    HashBlockConsumer512,
+    pad_final_block_512_wide,
+    pad_bytes_512_wide
+);
@@ -0,0 +1,85 @@
+use crate::{Digest, Keccak256Digest};
+use core::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Eip55Error {
+    MissingPrefix,
+    InvalidLength,
+    InvalidCharacter,
+    InvalidChecksum,
+}
+
+impl fmt::Display for Eip55Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let message = match self {
+            Eip55Error::MissingPrefix => "address does not begin with 0x",
+            Eip55Error::InvalidLength => "address is not 40 hexadecimal digits long",
+            Eip55Error::InvalidCharacter => "address contains a non-hexadecimal character",
+            Eip55Error::InvalidChecksum => "address has an invalid mixed-case checksum",
+        };
+        f.write_str(message)
+    }
+}
+
+impl std::error::Error for Eip55Error {}
+
+pub fn address(public_key: &[u8; 64]) -> [u8; 20] {
+    let hash = Keccak256Digest::hash(&public_key[..]);
+    let mut address = [0u8; 20];
+    address.copy_from_slice(&hash[12..32]);
+    address
+}
+
+pub fn encode(address: &[u8; 20]) -> String {
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+    let mut lower = [0u8; 40];
+    for (i, byte) in address.iter().enumerate() {
+        lower[2 * i] = DIGITS[(byte >> 4) as usize];
+        lower[2 * i + 1] = DIGITS[(byte & 0xf) as usize];
+    }
+    let hash = Keccak256Digest::hash(&lower[..]);
+
+    let mut encoded = String::with_capacity(42);
+    encoded.push_str("0x");
+    for (i, &digit) in lower.iter().enumerate() {
+        let nibble = (hash[i / 2] >> (4 * (1 - i % 2))) & 0xf;
+        encoded.push(if nibble >= 8 {
+            digit.to_ascii_uppercase() as char
+        } else {
+            digit as char
+        });
+    }
+    encoded
+}
+
+pub fn decode(encoded: &str) -> Result<[u8; 20], Eip55Error> {
+    let digits = encoded
+        .strip_prefix("0x")
+        .ok_or(Eip55Error::MissingPrefix)?
+        .as_bytes();
+
+    if digits.len() != 40 {
+        return Err(Eip55Error::InvalidLength);
+    }
+
+    let mut address = [0u8; 20];
+    for (i, &digit) in digits.iter().enumerate() {
+        let nibble = match digit {
+            b'0'..=b'9' => digit - b'0',
+            b'a'..=b'f' => digit - b'a' + 10,
+            b'A'..=b'F' => digit - b'A' + 10,
+            _ => return Err(Eip55Error::InvalidCharacter),
+        };
+        address[i / 2] |= nibble << (4 * (1 - i % 2));
+    }
+
+    let has_lower = digits.iter().any(|digit| digit.is_ascii_lowercase());
+    let has_upper = digits.iter().any(|digit| digit.is_ascii_uppercase());
+
+    if has_lower && has_upper && &encode(&address).as_bytes()[2..] != digits {
+        return Err(Eip55Error::InvalidChecksum);
+    }
+
+    Ok(address)
+}
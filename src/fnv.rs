@@ -0,0 +1,50 @@
+use crate::digest::Digest;
+
+const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const PRIME: u64 = 0x00000100000001b3;
+
+#[derive(Clone)]
+pub struct FNV1a64Digest {
+    seed: u64,
+    hash: u64,
+}
+
+impl FNV1a64Digest {
+    pub fn with_seed(seed: u64) -> Self {
+        Self { seed, hash: seed }
+    }
+}
+
+impl Digest<[u8; 8]> for FNV1a64Digest {
+    const BLOCK_SIZE: usize = 8;
+    const OUTPUT_SIZE: usize = 8;
+
+    fn new() -> Self {
+        Self::with_seed(OFFSET_BASIS)
+    }
+
+    fn hash(bytes: impl AsRef<[u8]>) -> [u8; 8] {
+        Self::new().chain(bytes).finalize()
+    }
+
+    fn update(&mut self, bytes: impl AsRef<[u8]>) {
+        for &byte in bytes.as_ref() {
+            self.hash = (self.hash ^ byte as u64).wrapping_mul(PRIME);
+        }
+    }
+
+    fn chain(mut self, bytes: impl AsRef<[u8]>) -> Self {
+        self.update(bytes);
+        self
+    }
+
+    fn finalize(mut self) -> [u8; 8] {
+        self.finalize_reset()
+    }
+
+    fn finalize_reset(&mut self) -> [u8; 8] {
+        let hash = self.hash;
+        self.hash = self.seed;
+        hash.to_be_bytes()
+    }
+}
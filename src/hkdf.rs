@@ -0,0 +1,58 @@
+use crate::digest::Digest;
+use crate::hmac::Hmac;
+use core::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HkdfError {
+    OutputTooLong,
+}
+
+impl fmt::Display for HkdfError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let message = match self {
+            HkdfError::OutputTooLong => "output is longer than 255 times the hash length",
+        };
+        f.write_str(message)
+    }
+}
+
+impl std::error::Error for HkdfError {}
+
+pub fn extract<D: Digest<Res> + Clone, Res: AsRef<[u8]>>(salt: &[u8], ikm: &[u8]) -> Res {
+    Hmac::<D>::mac(salt, ikm)
+}
+
+pub fn expand<D: Digest<Res> + Clone, Res: AsRef<[u8]>>(
+    prk: &[u8],
+    info: &[u8],
+    okm: &mut [u8],
+) -> Result<(), HkdfError> {
+    let hash_len = D::OUTPUT_SIZE;
+    if okm.len() > 255 * hash_len {
+        return Err(HkdfError::OutputTooLong);
+    }
+
+    let hmac = Hmac::<D>::new(prk);
+    let mut previous: Option<Res> = None;
+
+    for (i, chunk) in okm.chunks_mut(hash_len).enumerate() {
+        let mut block = hmac.clone();
+        if let Some(previous) = &previous {
+            block.update(previous);
+        }
+        let block = block.chain(info).chain([i as u8 + 1]).finalize();
+        chunk.copy_from_slice(&block.as_ref()[0..chunk.len()]);
+        previous = Some(block);
+    }
+
+    Ok(())
+}
+
+pub fn derive<D: Digest<Res> + Clone, Res: AsRef<[u8]>>(
+    salt: &[u8],
+    ikm: &[u8],
+    info: &[u8],
+    okm: &mut [u8],
+) -> Result<(), HkdfError> {
+    expand::<D, Res>(extract::<D, Res>(salt, ikm).as_ref(), info, okm)
+}
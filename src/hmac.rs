@@ -0,0 +1,100 @@
+use crate::digest::Digest;
+
+const IPAD: u8 = 0x36;
+const OPAD: u8 = 0x5c;
+
+pub fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len()
+        && a.iter()
+            .zip(b.iter())
+            .fold(0u8, |diff, (x, y)| diff | (x ^ y))
+            == 0
+}
+
+pub fn key_pads<D: Digest<Res>, Res: AsRef<[u8]>>(key: &[u8]) -> (Vec<u8>, Vec<u8>) {
+    let mut key_block = if key.len() > D::BLOCK_SIZE {
+        D::hash(key).as_ref().to_vec()
+    } else {
+        key.to_vec()
+    };
+    key_block.resize(D::BLOCK_SIZE.max(key_block.len()), 0);
+
+    let inner_pad = key_block.iter().map(|byte| byte ^ IPAD).collect();
+    let outer_pad = key_block.iter().map(|byte| byte ^ OPAD).collect();
+    (inner_pad, outer_pad)
+}
+
+#[derive(Clone)]
+pub struct Hmac<D> {
+    inner: D,
+    inner_initial: D,
+    outer_initial: D,
+}
+
+impl<D: Clone> Hmac<D> {
+    pub fn new<Res: AsRef<[u8]>>(key: impl AsRef<[u8]>) -> Self
+    where
+        D: Digest<Res>,
+    {
+        let (inner_pad, outer_pad) = key_pads::<D, Res>(key.as_ref());
+        let inner_initial = D::new().chain(inner_pad);
+        Self {
+            inner: inner_initial.clone(),
+            inner_initial,
+            outer_initial: D::new().chain(outer_pad),
+        }
+    }
+
+    pub fn mac<Res: AsRef<[u8]>>(key: impl AsRef<[u8]>, bytes: impl AsRef<[u8]>) -> Res
+    where
+        D: Digest<Res>,
+    {
+        Self::new(key).chain(bytes).finalize()
+    }
+
+    pub fn update<Res>(&mut self, bytes: impl AsRef<[u8]>)
+    where
+        D: Digest<Res>,
+    {
+        self.inner.update(bytes);
+    }
+
+    pub fn chain<Res>(mut self, bytes: impl AsRef<[u8]>) -> Self
+    where
+        D: Digest<Res>,
+    {
+        self.update(bytes);
+        self
+    }
+
+    pub fn finalize<Res: AsRef<[u8]>>(self) -> Res
+    where
+        D: Digest<Res>,
+    {
+        let inner = self.inner.finalize();
+        self.outer_initial.chain(inner).finalize()
+    }
+
+    pub fn finalize_reset<Res: AsRef<[u8]>>(&mut self) -> Res
+    where
+        D: Digest<Res>,
+    {
+        let inner = self.inner.finalize_reset();
+        self.inner = self.inner_initial.clone();
+        self.outer_initial.clone().chain(inner).finalize()
+    }
+
+    pub fn verify<Res: AsRef<[u8]>>(self, tag: &[u8]) -> bool
+    where
+        D: Digest<Res>,
+    {
+        constant_time_eq(self.finalize().as_ref(), tag)
+    }
+
+    pub fn verify_reset<Res: AsRef<[u8]>>(&mut self, tag: &[u8]) -> bool
+    where
+        D: Digest<Res>,
+    {
+        constant_time_eq(self.finalize_reset().as_ref(), tag)
+    }
+}
@@ -0,0 +1,174 @@
+use crate::digest::XofReader;
+use crate::sponge::{Sponge, SpongeReader};
+use core::mem::replace;
+use std::thread;
+
+const CHUNK_SIZE: usize = 8192;
+
+fn length_encode(value: u64) -> Vec<u8> {
+    let bytes = value.to_be_bytes();
+    let skip = bytes.iter().take_while(|&&byte| byte == 0).count();
+    let mut encoded = bytes[skip..].to_vec();
+    encoded.push((8 - skip) as u8);
+    encoded
+}
+
+#[derive(Clone)]
+pub struct KangarooTwelve<const RATE: usize, const CV: usize> {
+    final_node: Sponge<RATE, 12>,
+    customization: Vec<u8>,
+    threads: usize,
+    absorbed: usize,
+    buffer: Vec<u8>,
+    leaves: u64,
+}
+
+impl<const RATE: usize, const CV: usize> KangarooTwelve<RATE, CV> {
+    pub fn new(customization: &[u8]) -> Self {
+        Self {
+            final_node: Sponge::new(),
+            customization: customization.to_vec(),
+            threads: 1,
+            absorbed: 0,
+            buffer: Vec::with_capacity(CHUNK_SIZE),
+            leaves: 0,
+        }
+    }
+
+    pub fn threads(mut self, threads: usize) -> Self {
+        assert!(threads > 0);
+        self.threads = threads;
+        self
+    }
+
+    pub fn update(&mut self, bytes: impl AsRef<[u8]>) {
+        let mut bytes = bytes.as_ref();
+
+        if self.absorbed < CHUNK_SIZE {
+            let len = bytes.len().min(CHUNK_SIZE - self.absorbed);
+            self.final_node.absorb(&bytes[0..len]);
+            self.absorbed += len;
+            bytes = &bytes[len..];
+        }
+
+        if bytes.is_empty() {
+            return;
+        }
+
+        if self.leaves == 0 && self.buffer.is_empty() {
+            self.final_node.absorb(&[0x03, 0, 0, 0, 0, 0, 0, 0]);
+        }
+
+        if !self.buffer.is_empty() {
+            let len = bytes.len().min(CHUNK_SIZE - self.buffer.len());
+            self.buffer.extend_from_slice(&bytes[0..len]);
+            bytes = &bytes[len..];
+
+            if self.buffer.len() < CHUNK_SIZE {
+                return;
+            }
+
+            let chaining_values = self.chaining_values(&self.buffer);
+            self.absorb_chaining_values(&chaining_values);
+            self.buffer.clear();
+        }
+
+        let whole = bytes.len() - bytes.len() % CHUNK_SIZE;
+        let chaining_values = self.chaining_values(&bytes[0..whole]);
+        self.absorb_chaining_values(&chaining_values);
+        self.buffer.extend_from_slice(&bytes[whole..]);
+    }
+
+    pub fn chain(mut self, bytes: impl AsRef<[u8]>) -> Self {
+        self.update(bytes);
+        self
+    }
+
+    pub fn finalize(mut self, bytes: &mut [u8]) {
+        self.finalize_reset(bytes);
+    }
+
+    pub fn finalize_reset(&mut self, bytes: &mut [u8]) {
+        self.finish().read(bytes);
+    }
+
+    pub fn finalize_xof(mut self) -> SpongeReader<RATE, 12> {
+        self.finish()
+    }
+
+    pub fn finalize_xof_reset(&mut self) -> SpongeReader<RATE, 12> {
+        self.finish()
+    }
+
+    fn finish(&mut self) -> SpongeReader<RATE, 12> {
+        let mut suffix = self.customization.clone();
+        suffix.extend_from_slice(&length_encode(self.customization.len() as u64));
+        self.update(&suffix);
+
+        let domain = if self.leaves == 0 && self.buffer.is_empty() {
+            0x07
+        } else {
+            if !self.buffer.is_empty() {
+                let mut chaining_value = [0u8; CV];
+                Self::hash_leaf(&self.buffer, &mut chaining_value);
+                self.absorb_chaining_values(&chaining_value);
+            }
+            self.final_node.absorb(&length_encode(self.leaves));
+            self.final_node.absorb(&[0xff, 0xff]);
+            0x06
+        };
+
+        let reader = SpongeReader::new(replace(&mut self.final_node, Sponge::new()), domain);
+        self.absorbed = 0;
+        self.buffer.clear();
+        self.leaves = 0;
+        reader
+    }
+
+    fn absorb_chaining_values(&mut self, chaining_values: &[u8]) {
+        self.final_node.absorb(chaining_values);
+        self.leaves += (chaining_values.len() / CV) as u64;
+    }
+
+    fn chaining_values(&self, bytes: &[u8]) -> Vec<u8> {
+        let leaves = bytes.len() / CHUNK_SIZE;
+        let mut chaining_values = vec![0u8; leaves * CV];
+
+        if self.threads == 1 || leaves < 2 {
+            for (leaf, chaining_value) in
+                bytes.chunks(CHUNK_SIZE).zip(chaining_values.chunks_mut(CV))
+            {
+                Self::hash_leaf(leaf, chaining_value);
+            }
+        } else {
+            let per_thread = leaves.div_ceil(self.threads);
+
+            thread::scope(|scope| {
+                for (leaves, chaining_values) in bytes
+                    .chunks(per_thread * CHUNK_SIZE)
+                    .zip(chaining_values.chunks_mut(per_thread * CV))
+                {
+                    scope.spawn(move || {
+                        for (leaf, chaining_value) in leaves
+                            .chunks(CHUNK_SIZE)
+                            .zip(chaining_values.chunks_mut(CV))
+                        {
+                            Self::hash_leaf(leaf, chaining_value);
+                        }
+                    });
+                }
+            });
+        }
+
+        chaining_values
+    }
+
+    fn hash_leaf(leaf: &[u8], chaining_value: &mut [u8]) {
+        let mut sponge = Sponge::<RATE, 12>::new();
+        sponge.absorb(leaf);
+        SpongeReader::new(sponge, 0x0b).read(chaining_value);
+    }
+}
+
+pub type KT128 = KangarooTwelve<168, 32>;
+pub type KT256 = KangarooTwelve<136, 64>;
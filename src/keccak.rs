@@ -0,0 +1,65 @@
+const ROUND_CONSTANTS: [u64; 24] = [
+    0x0000000000000001u64,
+    0x0000000000008082u64,
+    0x800000000000808au64,
+    0x8000000080008000u64,
+    0x000000000000808bu64,
+    0x0000000080000001u64,
+    0x8000000080008081u64,
+    0x8000000000008009u64,
+    0x000000000000008au64,
+    0x0000000000000088u64,
+    0x0000000080008009u64,
+    0x000000008000000au64,
+    0x000000008000808bu64,
+    0x800000000000008bu64,
+    0x8000000000008089u64,
+    0x8000000000008003u64,
+    0x8000000000008002u64,
+    0x8000000000000080u64,
+    0x000000000000800au64,
+    0x800000008000000au64,
+    0x8000000080008081u64,
+    0x8000000000008080u64,
+    0x0000000080000001u64,
+    0x8000000080008008u64,
+];
+
+const ROTATIONS: [u32; 25] = [
+    0, 1, 62, 28, 27, 36, 44, 6, 55, 20, 3, 10, 43, 25, 39, 41, 45, 15, 21, 8, 18, 2, 61, 56, 14,
+];
+
+pub fn keccak_p1600(state: &mut [u64; 25], rounds: usize) {
+    for &rc in ROUND_CONSTANTS[24 - rounds..].iter() {
+        round(state, rc);
+    }
+}
+
+fn round(a: &mut [u64; 25], rc: u64) {
+    let mut c = [0u64; 5];
+    for (x, c) in c.iter_mut().enumerate() {
+        *c = a[x] ^ a[x + 5] ^ a[x + 10] ^ a[x + 15] ^ a[x + 20];
+    }
+
+    for x in 0..5 {
+        let d = c[(x + 4) % 5] ^ c[(x + 1) % 5].rotate_left(1);
+        for y in 0..5 {
+            a[x + 5 * y] ^= d;
+        }
+    }
+
+    let mut b = [0u64; 25];
+    for x in 0..5 {
+        for y in 0..5 {
+            b[y + 5 * ((2 * x + 3 * y) % 5)] = a[x + 5 * y].rotate_left(ROTATIONS[x + 5 * y]);
+        }
+    }
+
+    for x in 0..5 {
+        for y in 0..5 {
+            a[x + 5 * y] = b[x + 5 * y] ^ (!b[(x + 1) % 5 + 5 * y] & b[(x + 2) % 5 + 5 * y]);
+        }
+    }
+
+    a[0] ^= rc;
+}
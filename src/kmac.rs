@@ -0,0 +1,55 @@
+use crate::cshake::{absorb_bytepad, right_encode, CShake};
+use crate::digest::XofReader;
+use crate::sponge::SpongeReader;
+use core::mem::replace;
+
+#[derive(Clone)]
+pub struct Kmac<const RATE: usize> {
+    cshake: CShake<RATE>,
+    initial: CShake<RATE>,
+}
+
+impl<const RATE: usize> Kmac<RATE> {
+    pub fn new(key: &[u8], customization: &[u8]) -> Self {
+        let mut cshake = CShake::new(b"KMAC", customization);
+        absorb_bytepad(cshake.sponge_mut(), &[key]);
+        Self {
+            cshake: cshake.clone(),
+            initial: cshake,
+        }
+    }
+
+    pub fn update(&mut self, bytes: impl AsRef<[u8]>) {
+        self.cshake.update(bytes);
+    }
+
+    pub fn chain(mut self, bytes: impl AsRef<[u8]>) -> Self {
+        self.update(bytes);
+        self
+    }
+
+    pub fn finalize(self, bytes: &mut [u8]) {
+        Self::squeeze(self.cshake, bytes);
+    }
+
+    pub fn finalize_reset(&mut self, bytes: &mut [u8]) {
+        Self::squeeze(replace(&mut self.cshake, self.initial.clone()), bytes);
+    }
+
+    pub fn finalize_xof(self) -> SpongeReader<RATE> {
+        self.cshake.chain(right_encode(0).as_ref()).finalize()
+    }
+
+    pub fn finalize_xof_reset(&mut self) -> SpongeReader<RATE> {
+        let cshake = replace(&mut self.cshake, self.initial.clone());
+        cshake.chain(right_encode(0).as_ref()).finalize()
+    }
+
+    fn squeeze(cshake: CShake<RATE>, bytes: &mut [u8]) {
+        let encoded_len = right_encode(8 * bytes.len() as u64);
+        cshake.chain(encoded_len.as_ref()).finalize().read(bytes);
+    }
+}
+
+pub type KMAC128 = Kmac<168>;
+pub type KMAC256 = Kmac<136>;
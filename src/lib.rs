@@ -1,14 +1,83 @@
 // #![cfg_attr(not(test), no_std)]
-#![feature(trait_alias)]
 
+pub mod argon2;
+mod ascon;
+mod blake2;
+mod blake3;
+mod crc32c;
+mod cshake;
 mod digest;
+pub mod eip55;
+mod fnv;
+pub mod hkdf;
+mod hmac;
+mod kangaroo_twelve;
+mod keccak;
+mod kmac;
+mod md4;
+mod md5;
 mod padding;
+mod parallel_hash;
+pub mod pbkdf2;
+pub mod phc;
+mod ripemd;
+pub mod scrypt;
 mod sha1;
+mod sha1dc;
 mod sha256;
+mod sha3;
 mod sha512;
+pub mod sha_crypt;
+mod shake;
+mod skein;
+mod sm3;
+mod sponge;
+mod streebog;
 mod test;
+mod tiger;
+pub mod tth;
+mod tuple_hash;
+mod ubc_check;
+mod whirlpool;
+mod xxhash;
 
-pub use digest::Digest;
+pub use ascon::{AsconCXof128, AsconHash256Digest, AsconReader, AsconXof128};
+pub use blake2::{
+    Blake2b, Blake2b512Digest, Blake2bParams, Blake2s, Blake2s256Digest, Blake2sParams,
+};
+pub use blake3::{Blake3Digest, Blake3Reader};
+pub use crc32c::CRC32CDigest;
+pub use cshake::{CSHAKE128Xof, CSHAKE256Xof};
+pub use digest::{BitDigest, Digest, MidstateDigest, Xof, XofReader};
+pub use fnv::FNV1a64Digest;
+pub use hmac::Hmac;
+pub use kangaroo_twelve::{KangarooTwelve, KT128, KT256};
+pub use kmac::{KMAC128, KMAC256};
+pub use md4::MD4Digest;
+pub use md5::MD5Digest;
+pub use parallel_hash::{ParallelHash128, ParallelHash256};
+pub use ripemd::{RIPEMD128Digest, RIPEMD160Digest, RIPEMD256Digest, RIPEMD320Digest};
 pub use sha1::SHA1Digest;
+pub use sha1dc::{SHA1Collision, SHA1DCDigest, SHA1DCResult};
 pub use sha256::{SHA224Digest, SHA256Digest};
-pub use sha512::{SHA384Digest, SHA512Digest, SHA512x224Digest, SHA512x256Digest};
+pub use sha3::{
+    Keccak256Digest, Keccak512Digest, SHA3_224Digest, SHA3_256Digest, SHA3_384Digest,
+    SHA3_512Digest,
+};
+pub use sha512::{
+    SHA384Digest, SHA512Digest, SHA512tDigest, SHA512tOutput, SHA512x224Digest, SHA512x256Digest,
+};
+pub use shake::{
+    SHAKE128Reader, SHAKE128Xof, SHAKE256Reader, SHAKE256Xof, TurboSHAKE128Reader,
+    TurboSHAKE128Xof, TurboSHAKE256Reader, TurboSHAKE256Xof,
+};
+pub use skein::{
+    Skein1024, Skein1024x1024Digest, Skein256, Skein512, Skein512x256Digest, Skein512x512Digest,
+    Threefish1024, Threefish256, Threefish512,
+};
+pub use sm3::SM3Digest;
+pub use streebog::{Streebog256Digest, Streebog512Digest};
+pub use tiger::TigerDigest;
+pub use tuple_hash::{TupleHash128, TupleHash256};
+pub use whirlpool::WhirlpoolDigest;
+pub use xxhash::{XXH3x128Digest, XXH3x64Digest, XXH64Digest, XXH3};
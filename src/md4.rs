@@ -0,0 +1,121 @@
+use crate::digest::{Digest512, HashState, Schedule512};
+use crate::padding::LittleEndian;
+
+#[derive(Clone)]
+pub struct MD4State(u32, u32, u32, u32);
+
+type MD4Function = fn(u32, u32, u32) -> u32;
+
+#[allow(clippy::many_single_char_names)]
+impl HashState<[u8; 16], (MD4Function, u32, u32, u32)> for MD4State {
+    fn new() -> MD4State {
+        let a = 0x67452301u32;
+        let b = 0xefcdab89u32;
+        let c = 0x98badcfeu32;
+        let d = 0x10325476u32;
+        MD4State(a, b, c, d)
+    }
+
+    fn step(&self, (f, k, s, x): (MD4Function, u32, u32, u32)) -> MD4State {
+        let MD4State(a, b, c, d) = *self;
+        let t = a
+            .wrapping_add(f(b, c, d))
+            .wrapping_add(x)
+            .wrapping_add(k)
+            .rotate_left(s);
+        MD4State(d, t, b, c)
+    }
+
+    fn merge(&self, other: &MD4State) -> MD4State {
+        let MD4State(a, b, c, d) = self;
+        let MD4State(a_o, b_o, c_o, d_o) = other;
+        MD4State(
+            a.wrapping_add(*a_o),
+            b.wrapping_add(*b_o),
+            c.wrapping_add(*c_o),
+            d.wrapping_add(*d_o),
+        )
+    }
+
+    fn to_bytes(&self) -> [u8; 16] {
+        let mut bytes = [0; 16];
+        let MD4State(a, b, c, d) = self;
+        for (i, x) in [a, b, c, d].iter().enumerate() {
+            bytes[4 * i..4 * (i + 1)].copy_from_slice(&x.to_le_bytes())
+        }
+        bytes
+    }
+}
+
+pub struct MD4Schedule {
+    x: [u32; 16],
+    t: usize,
+}
+
+impl MD4Schedule {
+    const ORDER: [[usize; 16]; 3] = [
+        [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+        [0, 4, 8, 12, 1, 5, 9, 13, 2, 6, 10, 14, 3, 7, 11, 15],
+        [0, 8, 4, 12, 2, 10, 6, 14, 1, 9, 5, 13, 3, 11, 7, 15],
+    ];
+
+    const S: [[u32; 4]; 3] = [[3, 7, 11, 19], [3, 5, 9, 13], [3, 9, 11, 15]];
+}
+
+impl Schedule512 for MD4Schedule {
+    fn new(block: &[u8; 64]) -> MD4Schedule {
+        let mut x = [0u32; 16];
+
+        for (i, x) in x.iter_mut().enumerate() {
+            let base = 4 * i;
+            *x = u32::from_le_bytes([
+                block[base],
+                block[base + 1],
+                block[base + 2],
+                block[base + 3],
+            ]);
+        }
+
+        MD4Schedule { x, t: 0 }
+    }
+}
+
+impl Iterator for MD4Schedule {
+    type Item = (MD4Function, u32, u32, u32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        fn f(x: u32, y: u32, z: u32) -> u32 {
+            (x & y) | (!x & z)
+        }
+
+        fn g(x: u32, y: u32, z: u32) -> u32 {
+            (x & y) | (x & z) | (y & z)
+        }
+
+        fn h(x: u32, y: u32, z: u32) -> u32 {
+            x ^ y ^ z
+        }
+
+        if self.t < 48 {
+            let (round, t) = (self.t / 16, self.t % 16);
+            let (function, k) = if round == 0 {
+                (f as MD4Function, 0u32)
+            } else if round == 1 {
+                (g as MD4Function, 0x5a827999u32)
+            } else {
+                (h as MD4Function, 0x6ed9eba1u32)
+            };
+            self.t += 1;
+            Some((
+                function,
+                k,
+                Self::S[round][t % 4],
+                self.x[Self::ORDER[round][t]],
+            ))
+        } else {
+            None
+        }
+    }
+}
+
+pub type MD4Digest = Digest512<[u8; 16], MD4Schedule, MD4State, LittleEndian>;
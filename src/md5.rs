@@ -0,0 +1,189 @@
+use crate::digest::{Digest512, HashState, Schedule512};
+use crate::padding::LittleEndian;
+
+#[derive(Clone)]
+pub struct MD5State(u32, u32, u32, u32);
+
+type MD5Function = fn(u32, u32, u32) -> u32;
+
+#[allow(clippy::many_single_char_names)]
+impl HashState<[u8; 16], (MD5Function, u32, u32, u32)> for MD5State {
+    fn new() -> MD5State {
+        let a = 0x67452301u32;
+        let b = 0xefcdab89u32;
+        let c = 0x98badcfeu32;
+        let d = 0x10325476u32;
+        MD5State(a, b, c, d)
+    }
+
+    fn step(&self, (f, k, s, x): (MD5Function, u32, u32, u32)) -> MD5State {
+        let MD5State(a, b, c, d) = *self;
+        let t = a
+            .wrapping_add(f(b, c, d))
+            .wrapping_add(x)
+            .wrapping_add(k)
+            .rotate_left(s)
+            .wrapping_add(b);
+        MD5State(d, t, b, c)
+    }
+
+    fn merge(&self, other: &MD5State) -> MD5State {
+        let MD5State(a, b, c, d) = self;
+        let MD5State(a_o, b_o, c_o, d_o) = other;
+        MD5State(
+            a.wrapping_add(*a_o),
+            b.wrapping_add(*b_o),
+            c.wrapping_add(*c_o),
+            d.wrapping_add(*d_o),
+        )
+    }
+
+    fn to_bytes(&self) -> [u8; 16] {
+        let mut bytes = [0; 16];
+        let MD5State(a, b, c, d) = self;
+        for (i, x) in [a, b, c, d].iter().enumerate() {
+            bytes[4 * i..4 * (i + 1)].copy_from_slice(&x.to_le_bytes())
+        }
+        bytes
+    }
+}
+
+pub struct MD5Schedule {
+    x: [u32; 16],
+    t: usize,
+}
+
+impl MD5Schedule {
+    const K: [u32; 64] = [
+        0xd76aa478u32,
+        0xe8c7b756u32,
+        0x242070dbu32,
+        0xc1bdceeeu32,
+        0xf57c0fafu32,
+        0x4787c62au32,
+        0xa8304613u32,
+        0xfd469501u32,
+        0x698098d8u32,
+        0x8b44f7afu32,
+        0xffff5bb1u32,
+        0x895cd7beu32,
+        0x6b901122u32,
+        0xfd987193u32,
+        0xa679438eu32,
+        0x49b40821u32,
+        0xf61e2562u32,
+        0xc040b340u32,
+        0x265e5a51u32,
+        0xe9b6c7aau32,
+        0xd62f105du32,
+        0x02441453u32,
+        0xd8a1e681u32,
+        0xe7d3fbc8u32,
+        0x21e1cde6u32,
+        0xc33707d6u32,
+        0xf4d50d87u32,
+        0x455a14edu32,
+        0xa9e3e905u32,
+        0xfcefa3f8u32,
+        0x676f02d9u32,
+        0x8d2a4c8au32,
+        0xfffa3942u32,
+        0x8771f681u32,
+        0x6d9d6122u32,
+        0xfde5380cu32,
+        0xa4beea44u32,
+        0x4bdecfa9u32,
+        0xf6bb4b60u32,
+        0xbebfbc70u32,
+        0x289b7ec6u32,
+        0xeaa127fau32,
+        0xd4ef3085u32,
+        0x04881d05u32,
+        0xd9d4d039u32,
+        0xe6db99e5u32,
+        0x1fa27cf8u32,
+        0xc4ac5665u32,
+        0xf4292244u32,
+        0x432aff97u32,
+        0xab9423a7u32,
+        0xfc93a039u32,
+        0x655b59c3u32,
+        0x8f0ccc92u32,
+        0xffeff47du32,
+        0x85845dd1u32,
+        0x6fa87e4fu32,
+        0xfe2ce6e0u32,
+        0xa3014314u32,
+        0x4e0811a1u32,
+        0xf7537e82u32,
+        0xbd3af235u32,
+        0x2ad7d2bbu32,
+        0xeb86d391u32,
+    ];
+
+    const S: [[u32; 4]; 4] = [
+        [7, 12, 17, 22],
+        [5, 9, 14, 20],
+        [4, 11, 16, 23],
+        [6, 10, 15, 21],
+    ];
+}
+
+impl Schedule512 for MD5Schedule {
+    fn new(block: &[u8; 64]) -> MD5Schedule {
+        let mut x = [0u32; 16];
+
+        for (i, x) in x.iter_mut().enumerate() {
+            let base = 4 * i;
+            *x = u32::from_le_bytes([
+                block[base],
+                block[base + 1],
+                block[base + 2],
+                block[base + 3],
+            ]);
+        }
+
+        MD5Schedule { x, t: 0 }
+    }
+}
+
+impl Iterator for MD5Schedule {
+    type Item = (MD5Function, u32, u32, u32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        fn f(x: u32, y: u32, z: u32) -> u32 {
+            (x & y) | (!x & z)
+        }
+
+        fn g(x: u32, y: u32, z: u32) -> u32 {
+            (x & z) | (y & !z)
+        }
+
+        fn h(x: u32, y: u32, z: u32) -> u32 {
+            x ^ y ^ z
+        }
+
+        fn i(x: u32, y: u32, z: u32) -> u32 {
+            y ^ (x | !z)
+        }
+
+        if self.t < 64 {
+            let t = self.t;
+            let (function, index) = if t < 16 {
+                (f as MD5Function, t)
+            } else if t < 32 {
+                (g as MD5Function, (5 * t + 1) % 16)
+            } else if t < 48 {
+                (h as MD5Function, (3 * t + 5) % 16)
+            } else {
+                (i as MD5Function, (7 * t) % 16)
+            };
+            self.t += 1;
+            Some((function, Self::K[t], Self::S[t / 16][t % 4], self.x[index]))
+        } else {
+            None
+        }
+    }
+}
+
+pub type MD5Digest = Digest512<[u8; 16], MD5Schedule, MD5State, LittleEndian>;
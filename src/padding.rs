@@ -2,6 +2,23 @@ use core::marker::PhantomData;
 use core::mem::size_of;
 use core::ops::{Add, Mul, Rem};
 
+pub trait LengthPadding {
+    const LITTLE_ENDIAN: bool;
+    const TERMINATOR: u8 = 0x80;
+}
+
+pub struct BigEndian;
+
+pub struct LittleEndian;
+
+impl LengthPadding for BigEndian {
+    const LITTLE_ENDIAN: bool = false;
+}
+
+impl LengthPadding for LittleEndian {
+    const LITTLE_ENDIAN: bool = true;
+}
+
 macro_rules! counter_impl {
     ($name:ident, $underlying_type:ty) => {
         #[derive(Default, Clone, Copy)]
@@ -17,9 +34,9 @@ macro_rules! counter_impl {
             }
         }
 
-        impl Into<usize> for $name {
-            fn into(self) -> usize {
-                self.value as usize
+        impl From<$name> for usize {
+            fn from(counter: $name) -> usize {
+                counter.value as usize
             }
         }
 
@@ -54,8 +71,12 @@ macro_rules! counter_impl {
         }
 
         impl $name {
-            fn to_bytes(self) -> [u8; size_of::<$underlying_type>()] {
-                self.value.to_be_bytes()
+            fn to_bytes<P: LengthPadding>(self) -> [u8; size_of::<$underlying_type>()] {
+                if P::LITTLE_ENDIAN {
+                    self.value.to_le_bytes()
+                } else {
+                    self.value.to_be_bytes()
+                }
             }
         }
     };
@@ -64,6 +85,80 @@ macro_rules! counter_impl {
 counter_impl!(Counter64, u64);
 counter_impl!(Counter128, u128);
 
+#[derive(Default, Clone, Copy)]
+pub struct Counter256 {
+    limbs: [u64; 4],
+}
+
+impl From<usize> for Counter256 {
+    fn from(value: usize) -> Self {
+        Self {
+            limbs: [value as u64, 0, 0, 0],
+        }
+    }
+}
+
+impl From<Counter256> for usize {
+    fn from(counter: Counter256) -> usize {
+        counter.limbs[0] as usize
+    }
+}
+
+impl Add<usize> for Counter256 {
+    type Output = Self;
+
+    fn add(self, u: usize) -> Self {
+        let mut limbs = [0u64; 4];
+        let mut carry = u as u128;
+        for (limb, &value) in limbs.iter_mut().zip(self.limbs.iter()) {
+            let sum = value as u128 + carry;
+            *limb = sum as u64;
+            carry = sum >> 64;
+        }
+        Self { limbs }
+    }
+}
+
+impl Rem<usize> for Counter256 {
+    type Output = Self;
+
+    fn rem(self, u: usize) -> Self {
+        let remainder = self.limbs.iter().rev().fold(0u128, |remainder, &limb| {
+            ((remainder << 64) | limb as u128) % u as u128
+        });
+        Self::from(remainder as usize)
+    }
+}
+
+impl Mul<usize> for Counter256 {
+    type Output = Self;
+
+    fn mul(self, factor: usize) -> Self {
+        let mut limbs = [0u64; 4];
+        let mut carry = 0u128;
+        for (limb, &value) in limbs.iter_mut().zip(self.limbs.iter()) {
+            let product = value as u128 * factor as u128 + carry;
+            *limb = product as u64;
+            carry = product >> 64;
+        }
+        Self { limbs }
+    }
+}
+
+impl Counter256 {
+    fn to_bytes<P: LengthPadding>(self) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        for (i, limb) in self.limbs.iter().enumerate() {
+            if P::LITTLE_ENDIAN {
+                bytes[8 * i..8 * (i + 1)].copy_from_slice(&limb.to_le_bytes());
+            } else {
+                bytes[24 - 8 * i..32 - 8 * i].copy_from_slice(&limb.to_be_bytes());
+            }
+        }
+        bytes
+    }
+}
+
 macro_rules! consumer_trait {
     ($name:ident, $block_bytes:literal) => {
         pub trait $name<Res> {
@@ -79,23 +174,34 @@ consumer_trait!(BlockConsumer1024, 128);
 
 macro_rules! pad_final_block_fn {
     ($name:ident, $block_bytes:literal, $counter_type:ty, $consumer_trait:ident) => {
-        pub fn $name<Res, Consumer: $consumer_trait<Res>>(
+        pub fn $name<Res, P: LengthPadding, Consumer: $consumer_trait<Res>>(
             consumer: &mut Consumer,
             bytes: &[u8],
             len: $counter_type,
         ) {
-            assert!(bytes.len() < $block_bytes);
+            let partial_bits: usize = (len % 8).into();
+            let used = if partial_bits == 0 {
+                bytes.len() + 1
+            } else {
+                bytes.len()
+            };
+            assert!(used <= $block_bytes);
 
             let mut buffer = [0u8; $block_bytes];
 
             buffer[0..bytes.len()].copy_from_slice(bytes);
-            buffer[bytes.len()] = 0x80u8;
+            if partial_bits == 0 {
+                buffer[bytes.len()] = P::TERMINATOR;
+            } else {
+                buffer[used - 1] &= 0xff << (8 - partial_bits);
+                buffer[used - 1] |= P::TERMINATOR >> partial_bits;
+            }
 
-            let counter_bytes = (len * 8).to_bytes();
+            let counter_bytes = len.to_bytes::<P>();
 
-            if bytes.len() + 1 + counter_bytes.len() > $block_bytes {
+            if used + counter_bytes.len() > $block_bytes {
                 consumer.handle(&buffer);
-                for byte in buffer[0..bytes.len() + 1].iter_mut() {
+                for byte in buffer[0..used].iter_mut() {
                     *byte = 0;
                 }
             }
@@ -109,47 +215,95 @@ macro_rules! pad_final_block_fn {
 
 pad_final_block_fn!(pad_final_block_512, 64, Counter64, BlockConsumer512);
 pad_final_block_fn!(pad_final_block_1024, 128, Counter128, BlockConsumer1024);
+pad_final_block_fn!(pad_final_block_512_wide, 64, Counter256, BlockConsumer512);
 
 macro_rules! padder_impl {
     ($name:ident, $block_bytes:literal, $counter_type:ty, $consumer_trait:ident, $pad_fn:ident) => {
-        pub struct $name<Res, Consumer: $consumer_trait<Res>> {
+        pub struct $name<Res, P: LengthPadding, Consumer: $consumer_trait<Res>> {
             buffer: [u8; $block_bytes],
             len: $counter_type,
             consumer: Consumer,
             res: PhantomData<Res>,
+            padding: PhantomData<P>,
         }
 
-        impl<Res, Consumer: $consumer_trait<Res>> $name<Res, Consumer> {
+        impl<Res, P: LengthPadding, Consumer: $consumer_trait<Res> + Clone> Clone
+            for $name<Res, P, Consumer>
+        {
+            fn clone(&self) -> Self {
+                Self {
+                    buffer: self.buffer,
+                    len: self.len,
+                    consumer: self.consumer.clone(),
+                    res: PhantomData,
+                    padding: PhantomData,
+                }
+            }
+        }
+
+        impl<Res, P: LengthPadding, Consumer: $consumer_trait<Res>> $name<Res, P, Consumer> {
             pub fn new(consumer: Consumer) -> Self {
                 Self {
                     buffer: [0u8; $block_bytes],
                     len: <$counter_type>::default(),
                     consumer,
                     res: PhantomData,
+                    padding: PhantomData,
                 }
             }
 
             pub fn feed(&mut self, bytes: &[u8]) {
-                let buffer_len = self.buffer_len();
+                if self.partial_bits() != 0 {
+                    self.feed_bits(bytes, 8 * bytes.len());
+                    return;
+                }
 
-                if buffer_len + bytes.len() < $block_bytes {
-                    self.buffer[buffer_len..buffer_len + bytes.len()].copy_from_slice(bytes);
+                self.write(bytes);
+                self.len = self.len + 8 * bytes.len();
+            }
+
+            pub fn feed_bits(&mut self, bytes: &[u8], bit_len: usize) {
+                assert!(bit_len <= 8 * bytes.len());
+
+                let whole = bit_len / 8;
+                let rest = bit_len % 8;
+                let last = if rest != 0 {
+                    bytes[whole] & (0xff << (8 - rest))
                 } else {
-                    self.buffer[buffer_len..$block_bytes]
-                        .copy_from_slice(&bytes[0..$block_bytes - buffer_len]);
-                    self.consumer.handle(&self.buffer);
+                    0
+                };
+
+                let partial_bits = self.partial_bits();
+                if partial_bits == 0 {
+                    self.write(&bytes[0..whole]);
+                    self.len = self.len + bit_len;
+                    let buffer_len = self.buffer_len();
+                    self.buffer[buffer_len] = last;
+                    return;
+                }
 
-                    for block in bytes[$block_bytes - buffer_len..].chunks($block_bytes) {
-                        if block.len() == $block_bytes {
-                            self.buffer[0..$block_bytes].copy_from_slice(block);
-                            self.consumer.handle(&self.buffer);
-                        } else {
-                            self.buffer[0..block.len()].copy_from_slice(block);
-                        }
+                let mut carry = self.buffer[self.buffer_len()];
+                let mut shifted = [0u8; $block_bytes];
+
+                for chunk in bytes[0..whole].chunks($block_bytes) {
+                    for (shifted, &byte) in shifted.iter_mut().zip(chunk.iter()) {
+                        *shifted = carry | (byte >> partial_bits);
+                        carry = byte << (8 - partial_bits);
                     }
+                    self.write(&shifted[0..chunk.len()]);
+                    self.len = self.len + 8 * chunk.len();
+                }
+
+                if partial_bits + rest >= 8 {
+                    self.write(&[carry | (last >> partial_bits)]);
+                    carry = last << (8 - partial_bits);
+                } else {
+                    carry |= last >> partial_bits;
                 }
+                self.len = self.len + rest;
 
-                self.len = self.len + bytes.len();
+                let buffer_len = self.buffer_len();
+                self.buffer[buffer_len] = carry;
             }
 
             pub fn chain(mut self, bytes: &[u8]) -> Self {
@@ -158,20 +312,58 @@ macro_rules! padder_impl {
             }
 
             pub fn finalize(mut self) -> Res {
-                let buffer_len = self.buffer_len();
-                $pad_fn(&mut self.consumer, &self.buffer[0..buffer_len], self.len);
+                let buffer_len = self.buffer_len() + usize::from(self.partial_bits() != 0);
+                $pad_fn::<Res, P, Consumer>(
+                    &mut self.consumer,
+                    &self.buffer[0..buffer_len],
+                    self.len,
+                );
                 self.consumer.finalize()
             }
 
             pub fn finalize_reset(&mut self) -> Res {
-                let buffer_len = self.buffer_len();
-                $pad_fn(&mut self.consumer, &self.buffer[0..buffer_len], self.len);
+                let buffer_len = self.buffer_len() + usize::from(self.partial_bits() != 0);
+                $pad_fn::<Res, P, Consumer>(
+                    &mut self.consumer,
+                    &self.buffer[0..buffer_len],
+                    self.len,
+                );
                 self.len = <$counter_type>::default();
                 self.consumer.finalize_reset()
             }
 
+            #[allow(dead_code)]
+            pub fn consumer_mut(&mut self) -> &mut Consumer {
+                &mut self.consumer
+            }
+
+            fn write(&mut self, bytes: &[u8]) {
+                let buffer_len = self.buffer_len();
+
+                if buffer_len + bytes.len() < $block_bytes {
+                    self.buffer[buffer_len..buffer_len + bytes.len()].copy_from_slice(bytes);
+                } else {
+                    self.buffer[buffer_len..$block_bytes]
+                        .copy_from_slice(&bytes[0..$block_bytes - buffer_len]);
+                    self.consumer.handle(&self.buffer);
+
+                    for block in bytes[$block_bytes - buffer_len..].chunks($block_bytes) {
+                        if block.len() == $block_bytes {
+                            self.buffer[0..$block_bytes].copy_from_slice(block);
+                            self.consumer.handle(&self.buffer);
+                        } else {
+                            self.buffer[0..block.len()].copy_from_slice(block);
+                        }
+                    }
+                }
+            }
+
             fn buffer_len(&self) -> usize {
-                (self.len % $block_bytes).into()
+                usize::from(self.len % (8 * $block_bytes)) / 8
+            }
+
+            fn partial_bits(&self) -> usize {
+                (self.len % 8).into()
             }
         }
     };
@@ -191,10 +383,20 @@ padder_impl!(
     BlockConsumer1024,
     pad_final_block_1024
 );
+padder_impl!(
+    Padder512Wide,
+    64,
+    Counter256,
+    BlockConsumer512,
+    pad_final_block_512_wide
+);
 
 macro_rules! pad_bytes_fn {
     ($name:ident, $block_bytes:literal, $counter_type:ty, $consumer_trait:ident, $pad_final_block_fn:ident) => {
-        pub fn $name<Res, Consumer: $consumer_trait<Res>>(consumer: &mut Consumer, bytes: &[u8]) {
+        pub fn $name<Res, P: LengthPadding, Consumer: $consumer_trait<Res>>(
+            consumer: &mut Consumer,
+            bytes: &[u8],
+        ) {
             let mut buffer = [0u8; $block_bytes];
 
             for block in bytes.chunks($block_bytes) {
@@ -206,7 +408,11 @@ macro_rules! pad_bytes_fn {
             }
 
             let tail = &bytes[bytes.len() - bytes.len() % $block_bytes..];
-            $pad_final_block_fn(consumer, tail, <$counter_type>::from(bytes.len()));
+            $pad_final_block_fn::<Res, P, Consumer>(
+                consumer,
+                tail,
+                <$counter_type>::from(bytes.len()) * 8,
+            );
         }
     };
 }
@@ -225,3 +431,10 @@ pad_bytes_fn!(
     BlockConsumer1024,
     pad_final_block_1024
 );
+pad_bytes_fn!(
+    pad_bytes_512_wide,
+    64,
+    Counter256,
+    BlockConsumer512,
+    pad_final_block_512_wide
+);
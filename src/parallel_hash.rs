@@ -0,0 +1,146 @@
+use crate::cshake::{left_encode, right_encode, CShake};
+use crate::digest::XofReader;
+use crate::sponge::{Sponge, SpongeReader};
+use core::mem::replace;
+use std::thread;
+
+#[derive(Clone)]
+pub struct ParallelHash<const RATE: usize, const CV: usize> {
+    cshake: CShake<RATE>,
+    initial: CShake<RATE>,
+    block_size: usize,
+    threads: usize,
+    buffer: Vec<u8>,
+    blocks: u64,
+}
+
+impl<const RATE: usize, const CV: usize> ParallelHash<RATE, CV> {
+    pub fn new(block_size: usize, customization: &[u8]) -> Self {
+        assert!(block_size > 0);
+        let cshake = CShake::new(b"ParallelHash", customization)
+            .chain(left_encode(block_size as u64).as_ref());
+        Self {
+            cshake: cshake.clone(),
+            initial: cshake,
+            block_size,
+            threads: 1,
+            buffer: Vec::with_capacity(block_size),
+            blocks: 0,
+        }
+    }
+
+    pub fn threads(mut self, threads: usize) -> Self {
+        assert!(threads > 0);
+        self.threads = threads;
+        self
+    }
+
+    pub fn update(&mut self, bytes: impl AsRef<[u8]>) {
+        let mut bytes = bytes.as_ref();
+
+        if !self.buffer.is_empty() {
+            let len = bytes.len().min(self.block_size - self.buffer.len());
+            self.buffer.extend_from_slice(&bytes[0..len]);
+            bytes = &bytes[len..];
+
+            if self.buffer.len() < self.block_size {
+                return;
+            }
+
+            let chaining_values = self.chaining_values(&self.buffer);
+            self.absorb_chaining_values(&chaining_values);
+            self.buffer.clear();
+        }
+
+        let whole = bytes.len() - bytes.len() % self.block_size;
+        let chaining_values = self.chaining_values(&bytes[0..whole]);
+        self.absorb_chaining_values(&chaining_values);
+        self.buffer.extend_from_slice(&bytes[whole..]);
+    }
+
+    pub fn chain(mut self, bytes: impl AsRef<[u8]>) -> Self {
+        self.update(bytes);
+        self
+    }
+
+    pub fn finalize(mut self, bytes: &mut [u8]) {
+        self.finalize_reset(bytes);
+    }
+
+    pub fn finalize_reset(&mut self, bytes: &mut [u8]) {
+        self.finish(8 * bytes.len() as u64).read(bytes);
+    }
+
+    pub fn finalize_xof(mut self) -> SpongeReader<RATE> {
+        self.finish(0)
+    }
+
+    pub fn finalize_xof_reset(&mut self) -> SpongeReader<RATE> {
+        self.finish(0)
+    }
+
+    fn finish(&mut self, output_bits: u64) -> SpongeReader<RATE> {
+        if !self.buffer.is_empty() {
+            let mut chaining_value = [0u8; CV];
+            Self::hash_block(&self.buffer, &mut chaining_value);
+            self.absorb_chaining_values(&chaining_value);
+        }
+
+        self.cshake.update(right_encode(self.blocks).as_ref());
+        self.cshake.update(right_encode(output_bits).as_ref());
+
+        let reader = replace(&mut self.cshake, self.initial.clone()).finalize();
+        self.buffer.clear();
+        self.blocks = 0;
+        reader
+    }
+
+    fn absorb_chaining_values(&mut self, chaining_values: &[u8]) {
+        self.cshake.update(chaining_values);
+        self.blocks += (chaining_values.len() / CV) as u64;
+    }
+
+    fn chaining_values(&self, bytes: &[u8]) -> Vec<u8> {
+        let blocks = bytes.len() / self.block_size;
+        let mut chaining_values = vec![0u8; blocks * CV];
+
+        if self.threads == 1 || blocks < 2 {
+            for (block, chaining_value) in bytes
+                .chunks(self.block_size)
+                .zip(chaining_values.chunks_mut(CV))
+            {
+                Self::hash_block(block, chaining_value);
+            }
+        } else {
+            let per_thread = blocks.div_ceil(self.threads);
+            let block_size = self.block_size;
+
+            thread::scope(|scope| {
+                for (blocks, chaining_values) in bytes
+                    .chunks(per_thread * block_size)
+                    .zip(chaining_values.chunks_mut(per_thread * CV))
+                {
+                    scope.spawn(move || {
+                        for (block, chaining_value) in blocks
+                            .chunks(block_size)
+                            .zip(chaining_values.chunks_mut(CV))
+                        {
+                            Self::hash_block(block, chaining_value);
+                        }
+                    });
+                }
+            });
+        }
+
+        chaining_values
+    }
+
+    fn hash_block(block: &[u8], chaining_value: &mut [u8]) {
+        let mut sponge = Sponge::<RATE>::new();
+        sponge.absorb(block);
+        SpongeReader::new(sponge, 0x1f).read(chaining_value);
+    }
+}
+
+pub type ParallelHash128 = ParallelHash<168, 32>;
+pub type ParallelHash256 = ParallelHash<136, 64>;
@@ -0,0 +1,64 @@
+use crate::digest::MidstateDigest;
+use crate::hmac::key_pads;
+use core::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pbkdf2Error {
+    InvalidIterations,
+    OutputTooLong,
+}
+
+impl fmt::Display for Pbkdf2Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let message = match self {
+            Pbkdf2Error::InvalidIterations => "iterations must be positive",
+            Pbkdf2Error::OutputTooLong => "output is longer than 2^32 - 1 times the hash length",
+        };
+        f.write_str(message)
+    }
+}
+
+impl std::error::Error for Pbkdf2Error {}
+
+pub fn derive<D: MidstateDigest<Res>, Res: AsRef<[u8]>>(
+    password: &[u8],
+    salt: &[u8],
+    iterations: u32,
+    output: &mut [u8],
+) -> Result<(), Pbkdf2Error> {
+    if iterations == 0 {
+        return Err(Pbkdf2Error::InvalidIterations);
+    }
+
+    let hash_len = D::OUTPUT_SIZE;
+    if output.len().div_ceil(hash_len) > u32::MAX as usize {
+        return Err(Pbkdf2Error::OutputTooLong);
+    }
+
+    let (inner_pad, outer_pad) = key_pads::<D, Res>(password);
+    let inner = D::midstate(&inner_pad);
+    let outer = D::midstate(&outer_pad);
+
+    let mut message = salt.to_vec();
+    message.extend_from_slice(&[0u8; 4]);
+    let counter = salt.len();
+
+    for (i, chunk) in output.chunks_mut(hash_len).enumerate() {
+        message[counter..].copy_from_slice(&(i as u32 + 1).to_be_bytes());
+        let inner_hash = D::finalize_midstate(&inner, &message);
+        let mut u = D::finalize_midstate(&outer, inner_hash.as_ref());
+        let mut block = u.as_ref().to_vec();
+
+        for _ in 1..iterations {
+            let inner_hash = D::finalize_midstate(&inner, u.as_ref());
+            u = D::finalize_midstate(&outer, inner_hash.as_ref());
+            for (byte, u_byte) in block.iter_mut().zip(u.as_ref().iter()) {
+                *byte ^= u_byte;
+            }
+        }
+
+        chunk.copy_from_slice(&block[0..chunk.len()]);
+    }
+
+    Ok(())
+}
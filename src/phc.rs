@@ -0,0 +1,167 @@
+use core::fmt;
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PhcError {
+    InvalidFormat,
+    InvalidBase64,
+    UnsupportedAlgorithm,
+    MissingParameter,
+    InvalidParameter,
+}
+
+impl fmt::Display for PhcError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let message = match self {
+            PhcError::InvalidFormat => "string is not in PHC format",
+            PhcError::InvalidBase64 => "salt or hash is not valid unpadded base64",
+            PhcError::UnsupportedAlgorithm => "algorithm identifier is not supported",
+            PhcError::MissingParameter => "a required parameter is missing",
+            PhcError::InvalidParameter => "a parameter has an invalid value",
+        };
+        f.write_str(message)
+    }
+}
+
+impl std::error::Error for PhcError {}
+
+pub fn base64(bytes: &[u8]) -> String {
+    let mut encoded = String::with_capacity((8 * bytes.len()).div_ceil(6));
+    let mut buffer = 0u16;
+    let mut bits = 0;
+
+    for &byte in bytes {
+        buffer = (buffer << 8) | byte as u16;
+        bits += 8;
+        while bits >= 6 {
+            bits -= 6;
+            encoded.push(BASE64_ALPHABET[(buffer >> bits) as usize & 0x3f] as char);
+        }
+    }
+
+    if bits > 0 {
+        encoded.push(BASE64_ALPHABET[(buffer << (6 - bits)) as usize & 0x3f] as char);
+    }
+
+    encoded
+}
+
+pub fn decode_base64(encoded: &str) -> Result<Vec<u8>, PhcError> {
+    if encoded.len() % 4 == 1 {
+        return Err(PhcError::InvalidBase64);
+    }
+
+    let mut bytes = Vec::with_capacity(6 * encoded.len() / 8);
+    let mut buffer = 0u16;
+    let mut bits = 0;
+
+    for &digit in encoded.as_bytes() {
+        let value = BASE64_ALPHABET
+            .iter()
+            .position(|&c| c == digit)
+            .ok_or(PhcError::InvalidBase64)?;
+        buffer = (buffer << 6) | value as u16;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+        }
+    }
+
+    if buffer & ((1 << bits) - 1) != 0 {
+        return Err(PhcError::InvalidBase64);
+    }
+
+    Ok(bytes)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PhcString {
+    pub id: String,
+    pub version: Option<u32>,
+    pub params: Vec<(String, String)>,
+    pub salt: Vec<u8>,
+    pub hash: Vec<u8>,
+}
+
+impl PhcString {
+    pub fn parse(encoded: &str) -> Result<Self, PhcError> {
+        let mut fields = encoded
+            .strip_prefix('$')
+            .ok_or(PhcError::InvalidFormat)?
+            .split('$')
+            .collect::<Vec<&str>>();
+
+        if fields.len() < 3 || fields.len() > 5 {
+            return Err(PhcError::InvalidFormat);
+        }
+
+        let id = fields.remove(0);
+        if id.is_empty() {
+            return Err(PhcError::InvalidFormat);
+        }
+
+        let hash = decode_base64(fields.pop().unwrap())?;
+        let salt = decode_base64(fields.pop().unwrap())?;
+
+        let mut version = None;
+        if let Some(field) = fields.first().and_then(|field| field.strip_prefix("v=")) {
+            version = Some(field.parse().map_err(|_| PhcError::InvalidParameter)?);
+            fields.remove(0);
+        }
+
+        let params = match fields.as_slice() {
+            [] => Vec::new(),
+            [field] => field
+                .split(',')
+                .map(|param| {
+                    let (name, value) = param.split_once('=').ok_or(PhcError::InvalidFormat)?;
+                    Ok((name.to_owned(), value.to_owned()))
+                })
+                .collect::<Result<_, PhcError>>()?,
+            _ => return Err(PhcError::InvalidFormat),
+        };
+
+        Ok(Self {
+            id: id.to_owned(),
+            version,
+            params,
+            salt,
+            hash,
+        })
+    }
+
+    pub fn param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(param, _)| param == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn param_u32(&self, name: &str) -> Result<u32, PhcError> {
+        self.param(name)
+            .ok_or(PhcError::MissingParameter)?
+            .parse()
+            .map_err(|_| PhcError::InvalidParameter)
+    }
+}
+
+impl fmt::Display for PhcString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "${}", self.id)?;
+        if let Some(version) = self.version {
+            write!(f, "$v={}", version)?;
+        }
+        if !self.params.is_empty() {
+            let params = self
+                .params
+                .iter()
+                .map(|(name, value)| format!("{}={}", name, value))
+                .collect::<Vec<String>>();
+            write!(f, "${}", params.join(","))?;
+        }
+        write!(f, "${}${}", base64(&self.salt), base64(&self.hash))
+    }
+}
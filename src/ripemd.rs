@@ -0,0 +1,287 @@
+use crate::digest::{Digest512, HashState, Schedule512};
+use crate::padding::LittleEndian;
+
+const R_LEFT: [usize; 80] = [
+    0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 7, 4, 13, 1, 10, 6, 15, 3, 12, 0, 9, 5,
+    2, 14, 11, 8, 3, 10, 14, 4, 9, 15, 8, 1, 2, 7, 0, 6, 13, 11, 5, 12, 1, 9, 11, 10, 0, 8, 12, 4,
+    13, 3, 7, 15, 14, 5, 6, 2, 4, 0, 5, 9, 7, 12, 2, 10, 14, 1, 3, 8, 11, 6, 15, 13,
+];
+
+const R_RIGHT: [usize; 80] = [
+    5, 14, 7, 0, 9, 2, 11, 4, 13, 6, 15, 8, 1, 10, 3, 12, 6, 11, 3, 7, 0, 13, 5, 10, 14, 15, 8, 12,
+    4, 9, 1, 2, 15, 5, 1, 3, 7, 14, 6, 9, 11, 8, 12, 2, 10, 0, 4, 13, 8, 6, 4, 1, 3, 11, 15, 0, 5,
+    12, 2, 13, 9, 7, 10, 14, 12, 15, 10, 4, 1, 5, 8, 7, 6, 2, 13, 14, 0, 3, 9, 11,
+];
+
+const S_LEFT: [u32; 80] = [
+    11, 14, 15, 12, 5, 8, 7, 9, 11, 13, 14, 15, 6, 7, 9, 8, 7, 6, 8, 13, 11, 9, 7, 15, 7, 12, 15,
+    9, 11, 7, 13, 12, 11, 13, 6, 7, 14, 9, 13, 15, 14, 8, 13, 6, 5, 12, 7, 5, 11, 12, 14, 15, 14,
+    15, 9, 8, 9, 14, 5, 6, 8, 6, 5, 12, 9, 15, 5, 11, 6, 8, 13, 12, 5, 12, 13, 14, 11, 8, 5, 6,
+];
+
+const S_RIGHT: [u32; 80] = [
+    8, 9, 9, 11, 13, 15, 15, 5, 7, 7, 8, 11, 14, 14, 12, 6, 9, 13, 15, 7, 12, 8, 9, 11, 7, 7, 12,
+    7, 6, 15, 13, 11, 9, 7, 15, 11, 8, 6, 6, 14, 12, 13, 5, 14, 13, 13, 7, 5, 15, 5, 8, 11, 14, 14,
+    6, 14, 6, 9, 12, 9, 12, 5, 15, 8, 8, 5, 12, 9, 12, 5, 14, 6, 8, 13, 6, 5, 15, 13, 11, 11,
+];
+
+const K_LEFT: [u32; 5] = [0x00000000, 0x5a827999, 0x6ed9eba1, 0x8f1bbcdc, 0xa953fd4e];
+
+const K_RIGHT_128: [u32; 4] = [0x50a28be6, 0x5c4dd124, 0x6d703ef3, 0x00000000];
+
+const K_RIGHT_160: [u32; 5] = [0x50a28be6, 0x5c4dd124, 0x6d703ef3, 0x7a6d76e9, 0x00000000];
+
+fn f(round: usize, x: u32, y: u32, z: u32) -> u32 {
+    match round {
+        0 => x ^ y ^ z,
+        1 => (x & y) | (!x & z),
+        2 => (x | !y) ^ z,
+        3 => (x & z) | (y & !z),
+        _ => x ^ (y | !z),
+    }
+}
+
+fn step4(line: [u32; 4], round: usize, k: u32, x: u32, s: u32) -> [u32; 4] {
+    let [a, b, c, d] = line;
+    let t = a
+        .wrapping_add(f(round, b, c, d))
+        .wrapping_add(x)
+        .wrapping_add(k)
+        .rotate_left(s);
+    [d, t, b, c]
+}
+
+fn step5(line: [u32; 5], round: usize, k: u32, x: u32, s: u32) -> [u32; 5] {
+    let [a, b, c, d, e] = line;
+    let t = a
+        .wrapping_add(f(round, b, c, d))
+        .wrapping_add(x)
+        .wrapping_add(k)
+        .rotate_left(s)
+        .wrapping_add(e);
+    [e, t, b, c.rotate_left(10), d]
+}
+
+fn words_to_bytes(words: &[u32], bytes: &mut [u8]) {
+    for (word, bytes) in words.iter().zip(bytes.chunks_mut(4)) {
+        bytes.copy_from_slice(&word.to_le_bytes());
+    }
+}
+
+#[derive(Clone)]
+pub struct RIPEMD128State([u32; 4], [u32; 4]);
+
+impl HashState<[u8; 16], (usize, u32, u32)> for RIPEMD128State {
+    fn new() -> Self {
+        let h = [0x67452301u32, 0xefcdab89u32, 0x98badcfeu32, 0x10325476u32];
+        Self(h, h)
+    }
+
+    fn step(&self, (j, x_left, x_right): (usize, u32, u32)) -> Self {
+        let round = j / 16;
+        Self(
+            step4(self.0, round, K_LEFT[round], x_left, S_LEFT[j]),
+            step4(self.1, 3 - round, K_RIGHT_128[round], x_right, S_RIGHT[j]),
+        )
+    }
+
+    fn merge(&self, other: &Self) -> Self {
+        let Self(h, _) = self;
+        let Self(left, right) = other;
+        let h = [
+            h[1].wrapping_add(left[2]).wrapping_add(right[3]),
+            h[2].wrapping_add(left[3]).wrapping_add(right[0]),
+            h[3].wrapping_add(left[0]).wrapping_add(right[1]),
+            h[0].wrapping_add(left[1]).wrapping_add(right[2]),
+        ];
+        Self(h, h)
+    }
+
+    fn to_bytes(&self) -> [u8; 16] {
+        let mut bytes = [0u8; 16];
+        words_to_bytes(&self.0, &mut bytes);
+        bytes
+    }
+}
+
+#[derive(Clone)]
+pub struct RIPEMD160State([u32; 5], [u32; 5]);
+
+impl HashState<[u8; 20], (usize, u32, u32)> for RIPEMD160State {
+    fn new() -> Self {
+        let h = [
+            0x67452301u32,
+            0xefcdab89u32,
+            0x98badcfeu32,
+            0x10325476u32,
+            0xc3d2e1f0u32,
+        ];
+        Self(h, h)
+    }
+
+    fn step(&self, (j, x_left, x_right): (usize, u32, u32)) -> Self {
+        let round = j / 16;
+        Self(
+            step5(self.0, round, K_LEFT[round], x_left, S_LEFT[j]),
+            step5(self.1, 4 - round, K_RIGHT_160[round], x_right, S_RIGHT[j]),
+        )
+    }
+
+    fn merge(&self, other: &Self) -> Self {
+        let Self(h, _) = self;
+        let Self(left, right) = other;
+        let h = [
+            h[1].wrapping_add(left[2]).wrapping_add(right[3]),
+            h[2].wrapping_add(left[3]).wrapping_add(right[4]),
+            h[3].wrapping_add(left[4]).wrapping_add(right[0]),
+            h[4].wrapping_add(left[0]).wrapping_add(right[1]),
+            h[0].wrapping_add(left[1]).wrapping_add(right[2]),
+        ];
+        Self(h, h)
+    }
+
+    fn to_bytes(&self) -> [u8; 20] {
+        let mut bytes = [0u8; 20];
+        words_to_bytes(&self.0, &mut bytes);
+        bytes
+    }
+}
+
+#[derive(Clone)]
+pub struct RIPEMD256State([u32; 4], [u32; 4]);
+
+impl HashState<[u8; 32], (usize, u32, u32)> for RIPEMD256State {
+    fn new() -> Self {
+        Self(
+            [0x67452301u32, 0xefcdab89u32, 0x98badcfeu32, 0x10325476u32],
+            [0x76543210u32, 0xfedcba98u32, 0x89abcdefu32, 0x01234567u32],
+        )
+    }
+
+    fn step(&self, (j, x_left, x_right): (usize, u32, u32)) -> Self {
+        let round = j / 16;
+        let mut left = step4(self.0, round, K_LEFT[round], x_left, S_LEFT[j]);
+        let mut right = step4(self.1, 3 - round, K_RIGHT_128[round], x_right, S_RIGHT[j]);
+        if j % 16 == 15 {
+            core::mem::swap(&mut left[round], &mut right[round]);
+        }
+        Self(left, right)
+    }
+
+    fn merge(&self, other: &Self) -> Self {
+        let mut merged = self.clone();
+        for (h, x) in merged.0.iter_mut().zip(other.0.iter()) {
+            *h = h.wrapping_add(*x);
+        }
+        for (h, x) in merged.1.iter_mut().zip(other.1.iter()) {
+            *h = h.wrapping_add(*x);
+        }
+        merged
+    }
+
+    fn to_bytes(&self) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        words_to_bytes(&self.0, &mut bytes[0..16]);
+        words_to_bytes(&self.1, &mut bytes[16..32]);
+        bytes
+    }
+}
+
+#[derive(Clone)]
+pub struct RIPEMD320State([u32; 5], [u32; 5]);
+
+impl RIPEMD320State {
+    const SWAPS: [usize; 5] = [1, 3, 0, 2, 4];
+}
+
+impl HashState<[u8; 40], (usize, u32, u32)> for RIPEMD320State {
+    fn new() -> Self {
+        Self(
+            [
+                0x67452301u32,
+                0xefcdab89u32,
+                0x98badcfeu32,
+                0x10325476u32,
+                0xc3d2e1f0u32,
+            ],
+            [
+                0x76543210u32,
+                0xfedcba98u32,
+                0x89abcdefu32,
+                0x01234567u32,
+                0x3c2d1e0fu32,
+            ],
+        )
+    }
+
+    fn step(&self, (j, x_left, x_right): (usize, u32, u32)) -> Self {
+        let round = j / 16;
+        let mut left = step5(self.0, round, K_LEFT[round], x_left, S_LEFT[j]);
+        let mut right = step5(self.1, 4 - round, K_RIGHT_160[round], x_right, S_RIGHT[j]);
+        if j % 16 == 15 {
+            let swap = Self::SWAPS[round];
+            core::mem::swap(&mut left[swap], &mut right[swap]);
+        }
+        Self(left, right)
+    }
+
+    fn merge(&self, other: &Self) -> Self {
+        let mut merged = self.clone();
+        for (h, x) in merged.0.iter_mut().zip(other.0.iter()) {
+            *h = h.wrapping_add(*x);
+        }
+        for (h, x) in merged.1.iter_mut().zip(other.1.iter()) {
+            *h = h.wrapping_add(*x);
+        }
+        merged
+    }
+
+    fn to_bytes(&self) -> [u8; 40] {
+        let mut bytes = [0u8; 40];
+        words_to_bytes(&self.0, &mut bytes[0..20]);
+        words_to_bytes(&self.1, &mut bytes[20..40]);
+        bytes
+    }
+}
+
+pub struct RIPEMDSchedule<const ROUNDS: usize> {
+    x: [u32; 16],
+    j: usize,
+}
+
+impl<const ROUNDS: usize> Schedule512 for RIPEMDSchedule<ROUNDS> {
+    fn new(block: &[u8; 64]) -> Self {
+        let mut x = [0u32; 16];
+
+        for (i, x) in x.iter_mut().enumerate() {
+            let base = 4 * i;
+            *x = u32::from_le_bytes([
+                block[base],
+                block[base + 1],
+                block[base + 2],
+                block[base + 3],
+            ]);
+        }
+
+        Self { x, j: 0 }
+    }
+}
+
+impl<const ROUNDS: usize> Iterator for RIPEMDSchedule<ROUNDS> {
+    type Item = (usize, u32, u32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.j < 16 * ROUNDS {
+            let j = self.j;
+            self.j += 1;
+            Some((j, self.x[R_LEFT[j]], self.x[R_RIGHT[j]]))
+        } else {
+            None
+        }
+    }
+}
+
+pub type RIPEMD128Digest = Digest512<[u8; 16], RIPEMDSchedule<4>, RIPEMD128State, LittleEndian>;
+pub type RIPEMD160Digest = Digest512<[u8; 20], RIPEMDSchedule<5>, RIPEMD160State, LittleEndian>;
+pub type RIPEMD256Digest = Digest512<[u8; 32], RIPEMDSchedule<4>, RIPEMD256State, LittleEndian>;
+pub type RIPEMD320Digest = Digest512<[u8; 40], RIPEMDSchedule<5>, RIPEMD320State, LittleEndian>;
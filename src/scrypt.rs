@@ -0,0 +1,267 @@
+use crate::hmac::constant_time_eq;
+use crate::pbkdf2;
+use crate::phc::{PhcError, PhcString};
+use crate::sha256::SHA256Digest;
+use core::convert::{TryFrom, TryInto};
+use core::fmt;
+use std::thread;
+
+pub const MIN_HASH_LEN: usize = 16;
+pub const DEFAULT_MAX_MEMORY: usize = 1 << 31;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScryptError {
+    InvalidCost,
+    InvalidBlockSize,
+    InvalidParallelism,
+    InvalidHashLength,
+    MemoryLimitExceeded,
+    Phc(PhcError),
+}
+
+impl fmt::Display for ScryptError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let message = match self {
+            ScryptError::InvalidCost => {
+                "N must be a power of two greater than 1 and below 2^(16r), and 128 * r * N addressable"
+            }
+            ScryptError::InvalidBlockSize => "r must be positive",
+            ScryptError::InvalidParallelism => "p must be positive and r * p below 2^30",
+            ScryptError::InvalidHashLength => "hash is shorter than 16 bytes",
+            ScryptError::MemoryLimitExceeded => "parameters require more memory than allowed",
+            ScryptError::Phc(error) => return error.fmt(f),
+        };
+        f.write_str(message)
+    }
+}
+
+impl std::error::Error for ScryptError {}
+
+impl From<PhcError> for ScryptError {
+    fn from(error: PhcError) -> Self {
+        ScryptError::Phc(error)
+    }
+}
+
+fn salsa20_8(block: &mut [u32; 16]) {
+    let mut x = *block;
+
+    for _ in 0..4 {
+        for &(a, b, c, d) in [
+            (0, 4, 8, 12),
+            (5, 9, 13, 1),
+            (10, 14, 2, 6),
+            (15, 3, 7, 11),
+            (0, 1, 2, 3),
+            (5, 6, 7, 4),
+            (10, 11, 8, 9),
+            (15, 12, 13, 14),
+        ]
+        .iter()
+        {
+            x[b] ^= x[a].wrapping_add(x[d]).rotate_left(7);
+            x[c] ^= x[b].wrapping_add(x[a]).rotate_left(9);
+            x[d] ^= x[c].wrapping_add(x[b]).rotate_left(13);
+            x[a] ^= x[d].wrapping_add(x[c]).rotate_left(18);
+        }
+    }
+
+    for (word, x) in block.iter_mut().zip(x.iter()) {
+        *word = word.wrapping_add(*x);
+    }
+}
+
+fn block_mix(input: &[u32], output: &mut [u32]) {
+    let r = input.len() / 32;
+    let mut x: [u32; 16] = input[input.len() - 16..].try_into().unwrap();
+
+    for (i, chunk) in input.chunks(16).enumerate() {
+        for (word, input) in x.iter_mut().zip(chunk.iter()) {
+            *word ^= input;
+        }
+        salsa20_8(&mut x);
+
+        let position = (i / 2 + (i % 2) * r) * 16;
+        output[position..position + 16].copy_from_slice(&x);
+    }
+}
+
+fn ro_mix(block: &mut [u8], n: usize) {
+    let words = block.len() / 4;
+    let mut x: Vec<u32> = block
+        .chunks(4)
+        .map(|bytes| u32::from_le_bytes(bytes.try_into().unwrap()))
+        .collect();
+    let mut y = vec![0u32; words];
+    let mut v = vec![0u32; n * words];
+
+    for chunk in v.chunks_mut(words) {
+        chunk.copy_from_slice(&x);
+        block_mix(&chunk[..], &mut x);
+    }
+
+    for _ in 0..n {
+        let j = (x[words - 16] as u64 | (x[words - 15] as u64) << 32) as usize & (n - 1);
+        for (word, v) in x.iter_mut().zip(v[j * words..(j + 1) * words].iter()) {
+            *word ^= v;
+        }
+        block_mix(&x, &mut y);
+        core::mem::swap(&mut x, &mut y);
+    }
+
+    for (bytes, word) in block.chunks_mut(4).zip(x.iter()) {
+        bytes.copy_from_slice(&word.to_le_bytes());
+    }
+}
+
+fn memory(n: u64, r: u32, p: u32) -> Option<usize> {
+    let lane = 128usize.checked_mul(usize::try_from(r).ok()?)?;
+    let v = lane.checked_mul(usize::try_from(n).ok()?)?;
+    let b = lane.checked_mul(usize::try_from(p).ok()?)?;
+    v.checked_add(b)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Scrypt {
+    log_n: u8,
+    r: u32,
+    p: u32,
+    threads: usize,
+}
+
+impl Scrypt {
+    pub fn new(n: u64, r: u32, p: u32) -> Result<Self, ScryptError> {
+        if r == 0 {
+            return Err(ScryptError::InvalidBlockSize);
+        }
+        if p == 0 || r as u64 * p as u64 >= 1 << 30 {
+            return Err(ScryptError::InvalidParallelism);
+        }
+        if n < 2 || !n.is_power_of_two() || (r < 4 && n >= 1 << (16 * r)) {
+            return Err(ScryptError::InvalidCost);
+        }
+        if memory(n, r, p).is_none() {
+            return Err(ScryptError::InvalidCost);
+        }
+
+        Ok(Self {
+            log_n: n.trailing_zeros() as u8,
+            r,
+            p,
+            threads: 1,
+        })
+    }
+
+    pub fn threads(mut self, threads: usize) -> Self {
+        assert!(threads > 0);
+        self.threads = threads;
+        self
+    }
+
+    pub fn n(&self) -> u64 {
+        1 << self.log_n
+    }
+
+    pub fn r(&self) -> u32 {
+        self.r
+    }
+
+    pub fn p(&self) -> u32 {
+        self.p
+    }
+
+    pub fn memory(&self) -> usize {
+        memory(self.n(), self.r, self.p).unwrap()
+    }
+
+    pub fn derive(&self, password: &[u8], salt: &[u8], output: &mut [u8]) {
+        let n = usize::try_from(self.n()).unwrap();
+        let lane = 128 * self.r as usize;
+        let lanes = self.p as usize;
+
+        let mut blocks = vec![0u8; lanes * lane];
+        pbkdf2::derive::<SHA256Digest, _>(password, salt, 1, &mut blocks).unwrap();
+
+        if self.threads == 1 || lanes < 2 {
+            for block in blocks.chunks_mut(lane) {
+                ro_mix(block, n);
+            }
+        } else {
+            let per_thread = lanes.div_ceil(self.threads);
+
+            thread::scope(|scope| {
+                for blocks in blocks.chunks_mut(per_thread * lane) {
+                    scope.spawn(move || {
+                        for block in blocks.chunks_mut(lane) {
+                            ro_mix(block, n);
+                        }
+                    });
+                }
+            });
+        }
+
+        pbkdf2::derive::<SHA256Digest, _>(password, &blocks, 1, output).unwrap();
+    }
+
+    pub fn encode(&self, password: &[u8], salt: &[u8], len: usize) -> String {
+        let mut hash = vec![0u8; len];
+        self.derive(password, salt, &mut hash);
+
+        PhcString {
+            id: "scrypt".to_owned(),
+            version: None,
+            params: vec![
+                ("ln".to_owned(), self.log_n.to_string()),
+                ("r".to_owned(), self.r.to_string()),
+                ("p".to_owned(), self.p.to_string()),
+            ],
+            salt: salt.to_vec(),
+            hash,
+        }
+        .to_string()
+    }
+
+    pub fn decode(encoded: &str) -> Result<(Self, PhcString), ScryptError> {
+        Self::decode_with_max_memory(encoded, DEFAULT_MAX_MEMORY)
+    }
+
+    pub fn decode_with_max_memory(
+        encoded: &str,
+        max_memory: usize,
+    ) -> Result<(Self, PhcString), ScryptError> {
+        let phc = PhcString::parse(encoded)?;
+        if phc.id != "scrypt" || phc.version.is_some() {
+            return Err(PhcError::UnsupportedAlgorithm.into());
+        }
+
+        let log_n = phc.param_u32("ln")?;
+        if log_n >= 64 {
+            return Err(ScryptError::InvalidCost);
+        }
+
+        let scrypt = Self::new(1 << log_n, phc.param_u32("r")?, phc.param_u32("p")?)?;
+        if scrypt.memory() > max_memory {
+            return Err(ScryptError::MemoryLimitExceeded);
+        }
+        if phc.hash.len() < MIN_HASH_LEN {
+            return Err(ScryptError::InvalidHashLength);
+        }
+
+        Ok((scrypt, phc))
+    }
+
+    pub fn verify(password: &[u8], encoded: &str) -> Result<bool, ScryptError> {
+        Self::verify_with_max_memory(password, encoded, DEFAULT_MAX_MEMORY)
+    }
+
+    pub fn verify_with_max_memory(
+        password: &[u8],
+        encoded: &str,
+        max_memory: usize,
+    ) -> Result<bool, ScryptError> {
+        let (scrypt, phc) = Self::decode_with_max_memory(encoded, max_memory)?;
+        let mut hash = vec![0u8; phc.hash.len()];
+        scrypt.derive(password, &phc.salt, &mut hash);
+        Ok(constant_time_eq(&hash, &phc.hash))
+    }
+}
@@ -1,10 +1,23 @@
 use crate::digest::{Digest512, HashState, Schedule512};
 
-#[derive(Clone)]
+#[derive(Clone, PartialEq, Eq)]
 pub struct SHA1State(u32, u32, u32, u32, u32);
 
 type SHA1Function = fn(u32, u32, u32) -> u32;
 
+impl SHA1State {
+    pub fn unstep(&self, (f, k, w): (SHA1Function, u32, u32)) -> SHA1State {
+        let SHA1State(t, a, b, c, d) = *self;
+        let b = b.rotate_right(30);
+        let e = t
+            .wrapping_sub(a.rotate_left(5))
+            .wrapping_sub(f(b, c, d))
+            .wrapping_sub(k)
+            .wrapping_sub(w);
+        SHA1State(a, b, c, d, e)
+    }
+}
+
 #[allow(clippy::many_single_char_names)]
 impl HashState<[u8; 20], (SHA1Function, u32, u32)> for SHA1State {
     fn new() -> SHA1State {
@@ -72,8 +85,18 @@ impl Schedule512 for SHA1Schedule {
             w[i] = (w[i - 3] ^ w[i - 8] ^ w[i - 14] ^ w[i - 16]).rotate_left(1);
         }
 
+        SHA1Schedule::from_words(w)
+    }
+}
+
+impl SHA1Schedule {
+    pub fn from_words(w: [u32; 80]) -> SHA1Schedule {
         SHA1Schedule { w, t: 0 }
     }
+
+    pub fn words(&self) -> &[u32; 80] {
+        &self.w
+    }
 }
 
 impl Iterator for SHA1Schedule {
@@ -0,0 +1,176 @@
+use crate::digest::{BitDigest, Digest, HashState, Schedule512};
+use crate::padding::{BigEndian, BlockConsumer512, Padder512};
+use crate::sha1::{SHA1Schedule, SHA1State};
+use crate::ubc_check::{ubc_check, DISTURBANCE_VECTORS};
+use core::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SHA1Collision {
+    pub digest: [u8; 20],
+}
+
+impl fmt::Display for SHA1Collision {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("input contains a SHA-1 collision attack")
+    }
+}
+
+impl std::error::Error for SHA1Collision {}
+
+pub type SHA1DCResult = Result<[u8; 20], SHA1Collision>;
+
+#[derive(Clone)]
+struct SHA1DCConsumer {
+    state: SHA1State,
+    safe_hash: bool,
+    collision: bool,
+}
+
+impl SHA1DCConsumer {
+    fn new(safe_hash: bool) -> Self {
+        Self {
+            state: SHA1State::new(),
+            safe_hash,
+            collision: false,
+        }
+    }
+
+    fn compress(state: &SHA1State, schedule: SHA1Schedule) -> (SHA1State, [SHA1State; 2]) {
+        let mut checkpoints = [state.clone(), state.clone()];
+        let mut working = state.clone();
+        for (t, item) in schedule.enumerate() {
+            match t {
+                58 => checkpoints[0] = working.clone(),
+                65 => checkpoints[1] = working.clone(),
+                _ => (),
+            }
+            working = working.step(item);
+        }
+        (state.merge(&working), checkpoints)
+    }
+
+    fn recompress(w: [u32; 80], step: usize, checkpoint: &SHA1State) -> SHA1State {
+        let items: Vec<_> = SHA1Schedule::from_words(w).collect();
+        let initial = items[0..step]
+            .iter()
+            .rev()
+            .fold(checkpoint.clone(), |state, &item| state.unstep(item));
+        let last = items[step..]
+            .iter()
+            .fold(checkpoint.clone(), |state, &item| state.step(item));
+        initial.merge(&last)
+    }
+
+    fn result(&self) -> SHA1DCResult {
+        let digest = self.state.to_bytes();
+        if self.collision {
+            Err(SHA1Collision { digest })
+        } else {
+            Ok(digest)
+        }
+    }
+}
+
+impl BlockConsumer512<SHA1DCResult> for SHA1DCConsumer {
+    fn handle(&mut self, block: &[u8; 64]) {
+        let schedule = SHA1Schedule::new(block);
+        let w = *schedule.words();
+        let (mut state, checkpoints) = Self::compress(&self.state, schedule);
+
+        let mask = ubc_check(&w);
+        for dv in DISTURBANCE_VECTORS.iter().filter(|dv| mask & dv.mask != 0) {
+            let mut w_prime = w;
+            for (word, delta) in w_prime.iter_mut().zip(dv.dm.iter()) {
+                *word ^= delta;
+            }
+
+            let checkpoint = &checkpoints[if dv.step == 58 { 0 } else { 1 }];
+            if Self::recompress(w_prime, dv.step, checkpoint) == state {
+                self.collision = true;
+                if self.safe_hash {
+                    for _ in 0..2 {
+                        state = Self::compress(&state, SHA1Schedule::from_words(w)).0;
+                    }
+                }
+                break;
+            }
+        }
+
+        self.state = state;
+    }
+
+    fn finalize(self) -> SHA1DCResult {
+        self.result()
+    }
+
+    fn finalize_reset(&mut self) -> SHA1DCResult {
+        let result = self.result();
+        *self = Self::new(self.safe_hash);
+        result
+    }
+}
+
+#[derive(Clone)]
+pub struct SHA1DCDigest {
+    padder: Padder512<SHA1DCResult, BigEndian, SHA1DCConsumer>,
+}
+
+impl SHA1DCDigest {
+    pub fn safe_hash(mut self, enabled: bool) -> Self {
+        self.padder.consumer_mut().safe_hash = enabled;
+        self
+    }
+}
+
+impl Digest<SHA1DCResult> for SHA1DCDigest {
+    const BLOCK_SIZE: usize = 64;
+    const OUTPUT_SIZE: usize = 20;
+
+    fn new() -> Self {
+        Self {
+            padder: Padder512::new(SHA1DCConsumer::new(true)),
+        }
+    }
+
+    fn hash(bytes: impl AsRef<[u8]>) -> SHA1DCResult {
+        Self::new().chain(bytes).finalize()
+    }
+
+    fn update(&mut self, bytes: impl AsRef<[u8]>) {
+        self.padder.feed(bytes.as_ref());
+    }
+
+    fn chain(self, bytes: impl AsRef<[u8]>) -> Self {
+        Self {
+            padder: self.padder.chain(bytes.as_ref()),
+        }
+    }
+
+    fn finalize(self) -> SHA1DCResult {
+        self.padder.finalize()
+    }
+
+    fn finalize_reset(&mut self) -> SHA1DCResult {
+        self.padder.finalize_reset()
+    }
+}
+
+impl BitDigest<SHA1DCResult> for SHA1DCDigest {
+    fn hash_bits(bytes: impl AsRef<[u8]>, bit_len: usize) -> SHA1DCResult {
+        Self::new().finalize_bits(bytes, bit_len)
+    }
+
+    fn update_bits(&mut self, bytes: impl AsRef<[u8]>, bit_len: usize) {
+        self.padder.feed_bits(bytes.as_ref(), bit_len);
+    }
+
+    fn chain_bits(mut self, bytes: impl AsRef<[u8]>, bit_len: usize) -> Self {
+        self.update_bits(bytes, bit_len);
+        self
+    }
+
+    fn finalize_bits(mut self, bytes: impl AsRef<[u8]>, bit_len: usize) -> SHA1DCResult {
+        self.update_bits(bytes, bit_len);
+        self.finalize()
+    }
+}
@@ -0,0 +1,9 @@
+use crate::sponge::SpongeDigest;
+
+pub type SHA3_224Digest = SpongeDigest<144, 0x06, 28>;
+pub type SHA3_256Digest = SpongeDigest<136, 0x06, 32>;
+pub type SHA3_384Digest = SpongeDigest<104, 0x06, 48>;
+pub type SHA3_512Digest = SpongeDigest<72, 0x06, 64>;
+
+pub type Keccak256Digest = SpongeDigest<136, 0x01, 32>;
+pub type Keccak512Digest = SpongeDigest<72, 0x01, 64>;
@@ -1,24 +1,35 @@
 use crate::digest::{Digest1024, HashState, Schedule1024};
 
-#[derive(Clone)]
+#[derive(Clone, Copy)]
 pub struct SHA512State(u64, u64, u64, u64, u64, u64, u64, u64);
 
 #[allow(clippy::many_single_char_names)]
 impl SHA512State {
-    fn step(&self, (k, w): (u64, u64)) -> Self {
-        fn ch(x: u64, y: u64, z: u64) -> u64 {
+    const SHA512_IV: Self = Self(
+        0x6a09e667f3bcc908,
+        0xbb67ae8584caa73b,
+        0x3c6ef372fe94f82b,
+        0xa54ff53a5f1d36f1,
+        0x510e527fade682d1,
+        0x9b05688c2b3e6c1f,
+        0x1f83d9abfb41bd6b,
+        0x5be0cd19137e2179,
+    );
+
+    const fn step(&self, (k, w): (u64, u64)) -> Self {
+        const fn ch(x: u64, y: u64, z: u64) -> u64 {
             (x & y) ^ (!x & z)
         }
 
-        fn maj(x: u64, y: u64, z: u64) -> u64 {
+        const fn maj(x: u64, y: u64, z: u64) -> u64 {
             (x & y) ^ (x & z) ^ (y & z)
         }
 
-        fn big_sigma_0(x: u64) -> u64 {
+        const fn big_sigma_0(x: u64) -> u64 {
             x.rotate_right(28) ^ x.rotate_right(34) ^ x.rotate_right(39)
         }
 
-        fn big_sigma_1(x: u64) -> u64 {
+        const fn big_sigma_1(x: u64) -> u64 {
             x.rotate_right(14) ^ x.rotate_right(18) ^ x.rotate_right(41)
         }
 
@@ -32,7 +43,7 @@ impl SHA512State {
         Self(t1.wrapping_add(t2), a, b, c, d.wrapping_add(t1), e, f, g)
     }
 
-    fn merge(&self, other: &Self) -> Self {
+    const fn merge(&self, other: &Self) -> Self {
         let Self(a, b, c, d, e, f, g, h) = *self;
         let Self(a_o, b_o, c_o, d_o, e_o, f_o, g_o, h_o) = *other;
         Self(
@@ -51,15 +62,7 @@ impl SHA512State {
 #[allow(clippy::many_single_char_names)]
 impl HashState<[u8; 64], (u64, u64)> for SHA512State {
     fn new() -> Self {
-        let a = 0x6a09e667f3bcc908u64;
-        let b = 0xbb67ae8584caa73bu64;
-        let c = 0x3c6ef372fe94f82bu64;
-        let d = 0xa54ff53a5f1d36f1u64;
-        let e = 0x510e527fade682d1u64;
-        let f = 0x9b05688c2b3e6c1fu64;
-        let g = 0x1f83d9abfb41bd6bu64;
-        let h = 0x5be0cd19137e2179u64;
-        Self(a, b, c, d, e, f, g, h)
+        Self::SHA512_IV
     }
 
     fn step(&self, (k, w): (u64, u64)) -> Self {
@@ -177,6 +180,102 @@ impl HashState<[u8; 32], (u64, u64)> for SHA512State {
     }
 }
 
+const fn sha512t_iv(t: usize) -> SHA512State {
+    let mut block = [0u8; 128];
+    let mut len = 0;
+
+    let prefix = b"SHA-512/";
+    while len < prefix.len() {
+        block[len] = prefix[len];
+        len += 1;
+    }
+
+    let mut divisor = 1;
+    while divisor * 10 <= t {
+        divisor *= 10;
+    }
+    while divisor > 0 {
+        block[len] = b'0' + (t / divisor % 10) as u8;
+        len += 1;
+        divisor /= 10;
+    }
+
+    block[len] = 0x80;
+    block[127] = 8 * len as u8;
+
+    let mask = 0xa5a5a5a5a5a5a5a5u64;
+    let SHA512State(a, b, c, d, e, f, g, h) = SHA512State::SHA512_IV;
+    let initial = SHA512State(
+        a ^ mask,
+        b ^ mask,
+        c ^ mask,
+        d ^ mask,
+        e ^ mask,
+        f ^ mask,
+        g ^ mask,
+        h ^ mask,
+    );
+
+    let w = SHA512Schedule::expand(&block);
+    let mut state = initial;
+    let mut i = 0;
+    while i < 80 {
+        state = state.step((SHA512Schedule::K[i], w[i]));
+        i += 1;
+    }
+    initial.merge(&state)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SHA512tOutput<const T: usize>([u8; 64]);
+
+impl<const T: usize> AsRef<[u8]> for SHA512tOutput<T> {
+    fn as_ref(&self) -> &[u8] {
+        &self.0[0..T.div_ceil(8)]
+    }
+}
+
+impl<const T: usize> From<SHA512tOutput<T>> for Vec<u8> {
+    fn from(output: SHA512tOutput<T>) -> Vec<u8> {
+        output.as_ref().to_vec()
+    }
+}
+
+#[derive(Clone)]
+pub struct SHA512tState<const T: usize>(SHA512State);
+
+impl<const T: usize> SHA512tState<T> {
+    const IV: SHA512State = {
+        assert!(T > 0 && T < 512 && T != 384);
+        sha512t_iv(T)
+    };
+}
+
+impl<const T: usize> HashState<SHA512tOutput<T>, (u64, u64)> for SHA512tState<T> {
+    const OUTPUT_SIZE: usize = T.div_ceil(8);
+
+    fn new() -> Self {
+        Self(Self::IV)
+    }
+
+    fn step(&self, (k, w): (u64, u64)) -> Self {
+        Self(self.0.step((k, w)))
+    }
+
+    fn merge(&self, other: &Self) -> Self {
+        Self(self.0.merge(&other.0))
+    }
+
+    fn to_bytes(&self) -> SHA512tOutput<T> {
+        let mut bytes = HashState::<[u8; 64], (u64, u64)>::to_bytes(&self.0);
+        if !T.is_multiple_of(8) {
+            bytes[T / 8] &= 0xffu8 << (8 - T % 8);
+        }
+        bytes[T.div_ceil(8)..].fill(0);
+        SHA512tOutput(bytes)
+    }
+}
+
 pub struct SHA512Schedule {
     w: [u64; 80],
     t: usize,
@@ -267,11 +366,12 @@ impl SHA512Schedule {
     ];
 }
 
-impl Schedule1024 for SHA512Schedule {
-    fn new(block: &[u8; 128]) -> Self {
+impl SHA512Schedule {
+    const fn expand(block: &[u8; 128]) -> [u64; 80] {
         let mut w = [0u64; 80];
 
-        for (i, w) in w[0..16].iter_mut().enumerate() {
+        let mut i = 0;
+        while i < 16 {
             let base = 8 * i;
             let bytes = [
                 block[base],
@@ -283,24 +383,35 @@ impl Schedule1024 for SHA512Schedule {
                 block[base + 6],
                 block[base + 7],
             ];
-            *w = u64::from_be_bytes(bytes);
+            w[i] = u64::from_be_bytes(bytes);
+            i += 1;
         }
 
-        fn sigma_0(x: u64) -> u64 {
+        const fn sigma_0(x: u64) -> u64 {
             x.rotate_right(1) ^ x.rotate_right(8) ^ (x >> 7)
         }
-        fn sigma_1(x: u64) -> u64 {
+        const fn sigma_1(x: u64) -> u64 {
             x.rotate_right(19) ^ x.rotate_right(61) ^ (x >> 6)
         }
 
-        for i in 16..80 {
+        while i < 80 {
             w[i] = sigma_1(w[i - 2])
                 .wrapping_add(w[i - 7])
                 .wrapping_add(sigma_0(w[i - 15]))
                 .wrapping_add(w[i - 16]);
+            i += 1;
         }
 
-        Self { w, t: 0 }
+        w
+    }
+}
+
+impl Schedule1024 for SHA512Schedule {
+    fn new(block: &[u8; 128]) -> Self {
+        Self {
+            w: Self::expand(block),
+            t: 0,
+        }
     }
 }
 
@@ -322,3 +433,5 @@ pub type SHA512Digest = Digest1024<[u8; 64], SHA512Schedule, SHA512State>;
 pub type SHA384Digest = Digest1024<[u8; 48], SHA512Schedule, SHA512State>;
 pub type SHA512x224Digest = Digest1024<[u8; 28], SHA512Schedule, SHA512State>;
 pub type SHA512x256Digest = Digest1024<[u8; 32], SHA512Schedule, SHA512State>;
+pub type SHA512tDigest<const T: usize> =
+    Digest1024<SHA512tOutput<T>, SHA512Schedule, SHA512tState<T>>;
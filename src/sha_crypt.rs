@@ -0,0 +1,181 @@
+use crate::digest::Digest;
+use crate::hmac::constant_time_eq;
+use crate::sha256::SHA256Digest;
+use crate::sha512::SHA512Digest;
+use core::convert::TryFrom;
+use core::fmt;
+
+pub const DEFAULT_ROUNDS: u32 = 5000;
+pub const MIN_ROUNDS: u32 = 1000;
+pub const MAX_ROUNDS: u32 = 999_999_999;
+
+const MAX_SALT_LEN: usize = 16;
+
+const ALPHABET: &[u8; 64] = b"./0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+const SHA256_ORDER: [usize; 32] = [
+    0, 10, 20, 21, 1, 11, 12, 22, 2, 3, 13, 23, 24, 4, 14, 15, 25, 5, 6, 16, 26, 27, 7, 17, 18, 28,
+    8, 9, 19, 29, 31, 30,
+];
+
+const SHA512_ORDER: [usize; 64] = [
+    0, 21, 42, 22, 43, 1, 44, 2, 23, 3, 24, 45, 25, 46, 4, 47, 5, 26, 6, 27, 48, 28, 49, 7, 50, 8,
+    29, 9, 30, 51, 31, 52, 10, 53, 11, 32, 12, 33, 54, 34, 55, 13, 56, 14, 35, 15, 36, 57, 37, 58,
+    16, 59, 17, 38, 18, 39, 60, 40, 61, 19, 62, 20, 41, 63,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShaCryptError {
+    InvalidFormat,
+    UnsupportedAlgorithm,
+    InvalidRounds,
+}
+
+impl fmt::Display for ShaCryptError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let message = match self {
+            ShaCryptError::InvalidFormat => "string is not in SHA-crypt format",
+            ShaCryptError::UnsupportedAlgorithm => "algorithm identifier is not $5$ or $6$",
+            ShaCryptError::InvalidRounds => "rounds is not a decimal number",
+        };
+        f.write_str(message)
+    }
+}
+
+impl std::error::Error for ShaCryptError {}
+
+fn repeat(bytes: &[u8], len: usize) -> Vec<u8> {
+    bytes.iter().cycle().take(len).copied().collect()
+}
+
+fn base64(hash: &[u8], order: &[usize]) -> String {
+    let mut encoded = String::with_capacity((8 * hash.len()).div_ceil(6));
+
+    for group in order.chunks(3) {
+        let mut word = group
+            .iter()
+            .fold(0u32, |word, &i| (word << 8) | hash[i] as u32);
+        for _ in 0..=group.len() {
+            encoded.push(ALPHABET[word as usize & 0x3f] as char);
+            word >>= 6;
+        }
+    }
+
+    encoded
+}
+
+fn crypt<D: Digest<Res>, Res: AsRef<[u8]>>(
+    id: &str,
+    order: &[usize],
+    password: &[u8],
+    salt: &str,
+    rounds: Option<u32>,
+) -> String {
+    let salt = salt.as_bytes();
+    let salt = &salt[0..salt
+        .iter()
+        .position(|&byte| byte == b'$')
+        .unwrap_or(salt.len())
+        .min(MAX_SALT_LEN)];
+
+    let b = D::new()
+        .chain(password)
+        .chain(salt)
+        .chain(password)
+        .finalize();
+
+    let mut a = D::new()
+        .chain(password)
+        .chain(salt)
+        .chain(repeat(b.as_ref(), password.len()));
+    let mut len = password.len();
+    while len > 0 {
+        if len & 1 == 1 {
+            a.update(&b);
+        } else {
+            a.update(password);
+        }
+        len >>= 1;
+    }
+    let a = a.finalize();
+
+    let mut dp = D::new();
+    for _ in 0..password.len() {
+        dp.update(password);
+    }
+    let p = repeat(dp.finalize().as_ref(), password.len());
+
+    let mut ds = D::new();
+    for _ in 0..16 + a.as_ref()[0] as usize {
+        ds.update(salt);
+    }
+    let s = repeat(ds.finalize().as_ref(), salt.len());
+
+    let rounds = rounds.map(|rounds| rounds.clamp(MIN_ROUNDS, MAX_ROUNDS));
+    let mut c = a;
+    for i in 0..rounds.unwrap_or(DEFAULT_ROUNDS) {
+        let mut digest = D::new();
+        if i % 2 == 1 {
+            digest.update(&p);
+        } else {
+            digest.update(&c);
+        }
+        if i % 3 != 0 {
+            digest.update(&s);
+        }
+        if i % 7 != 0 {
+            digest.update(&p);
+        }
+        if i % 2 == 1 {
+            digest.update(&c);
+        } else {
+            digest.update(&p);
+        }
+        c = digest.finalize();
+    }
+
+    let mut encoded = format!("${}$", id);
+    if let Some(rounds) = rounds {
+        encoded.push_str(&format!("rounds={}$", rounds));
+    }
+    encoded.push_str(&String::from_utf8_lossy(salt));
+    encoded.push('$');
+    encoded.push_str(&base64(c.as_ref(), order));
+    encoded
+}
+
+pub fn sha256_crypt(password: &[u8], salt: &str, rounds: Option<u32>) -> String {
+    crypt::<SHA256Digest, _>("5", &SHA256_ORDER, password, salt, rounds)
+}
+
+pub fn sha512_crypt(password: &[u8], salt: &str, rounds: Option<u32>) -> String {
+    crypt::<SHA512Digest, _>("6", &SHA512_ORDER, password, salt, rounds)
+}
+
+pub fn verify(password: &[u8], encoded: &str) -> Result<bool, ShaCryptError> {
+    let (id, rest) = encoded
+        .strip_prefix('$')
+        .and_then(|rest| rest.split_once('$'))
+        .ok_or(ShaCryptError::InvalidFormat)?;
+
+    let (rounds, rest) = match rest.strip_prefix("rounds=") {
+        Some(rest) => {
+            let (rounds, rest) = rest.split_once('$').ok_or(ShaCryptError::InvalidFormat)?;
+            let rounds = rounds
+                .parse::<u64>()
+                .map_err(|_| ShaCryptError::InvalidRounds)?;
+            (Some(u32::try_from(rounds).unwrap_or(MAX_ROUNDS)), rest)
+        }
+        None => (None, rest),
+    };
+
+    let (salt, _) = rest.split_once('$').ok_or(ShaCryptError::InvalidFormat)?;
+
+    let computed = match id {
+        "5" => sha256_crypt(password, salt, rounds),
+        "6" => sha512_crypt(password, salt, rounds),
+        _ => return Err(ShaCryptError::UnsupportedAlgorithm),
+    };
+
+    Ok(constant_time_eq(computed.as_bytes(), encoded.as_bytes()))
+}
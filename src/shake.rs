@@ -0,0 +1,23 @@
+use crate::sponge::{SpongeReader, SpongeXof};
+
+pub type SHAKE128Xof = SpongeXof<168, 0x1f>;
+pub type SHAKE256Xof = SpongeXof<136, 0x1f>;
+pub type SHAKE128Reader = SpongeReader<168>;
+pub type SHAKE256Reader = SpongeReader<136>;
+
+/// `DOMAIN` must be in `0x01..=0x7f`; other values are rejected at compile time.
+///
+/// ```compile_fail
+/// use petrel::{TurboSHAKE128Xof, Xof};
+/// TurboSHAKE128Xof::<0x80>::new().finalize();
+/// ```
+pub type TurboSHAKE128Xof<const DOMAIN: u8 = 0x1f> = SpongeXof<168, DOMAIN, 12>;
+/// `DOMAIN` must be in `0x01..=0x7f`; other values are rejected at compile time.
+///
+/// ```compile_fail
+/// use petrel::{TurboSHAKE256Xof, Xof};
+/// TurboSHAKE256Xof::<0x00>::new().finalize();
+/// ```
+pub type TurboSHAKE256Xof<const DOMAIN: u8 = 0x1f> = SpongeXof<136, DOMAIN, 12>;
+pub type TurboSHAKE128Reader = SpongeReader<168, 12>;
+pub type TurboSHAKE256Reader = SpongeReader<136, 12>;
@@ -0,0 +1,349 @@
+use crate::digest::Digest;
+use core::convert::TryInto;
+
+const C240: u64 = 0x1bd11bdaa9fc1a22;
+
+const TYPE_KEY: u64 = 0;
+const TYPE_CONFIG: u64 = 4;
+const TYPE_MESSAGE: u64 = 48;
+const TYPE_OUTPUT: u64 = 63;
+
+const FIRST: u64 = 1 << 62;
+const FINAL: u64 = 1 << 63;
+
+macro_rules! threefish_impl {
+    ($name:ident, $words:literal, $rounds:literal, $rotations:expr, $permutation:expr) => {
+        #[derive(Clone)]
+        pub struct $name {
+            key: [u64; $words + 1],
+            tweak: [u64; 3],
+        }
+
+        impl $name {
+            const ROTATIONS: [[u32; $words / 2]; 8] = $rotations;
+            const PERMUTATION: [usize; $words] = $permutation;
+
+            pub fn new(key: &[u8; 8 * $words], tweak: &[u8; 16]) -> Self {
+                let mut key_words = [0u64; $words];
+                for (word, bytes) in key_words.iter_mut().zip(key.chunks(8)) {
+                    *word = u64::from_le_bytes(bytes.try_into().unwrap());
+                }
+                Self::from_words(
+                    &key_words,
+                    [
+                        u64::from_le_bytes(tweak[0..8].try_into().unwrap()),
+                        u64::from_le_bytes(tweak[8..16].try_into().unwrap()),
+                    ],
+                )
+            }
+
+            fn from_words(key: &[u64; $words], tweak: [u64; 2]) -> Self {
+                let mut extended_key = [0u64; $words + 1];
+                extended_key[0..$words].copy_from_slice(key);
+                extended_key[$words] = key.iter().fold(C240, |parity, word| parity ^ word);
+                Self {
+                    key: extended_key,
+                    tweak: [tweak[0], tweak[1], tweak[0] ^ tweak[1]],
+                }
+            }
+
+            fn subkey(&self, s: usize) -> [u64; $words] {
+                let mut subkey = [0u64; $words];
+                for (i, word) in subkey.iter_mut().enumerate() {
+                    *word = self.key[(s + i) % ($words + 1)];
+                }
+                subkey[$words - 3] = subkey[$words - 3].wrapping_add(self.tweak[s % 3]);
+                subkey[$words - 2] = subkey[$words - 2].wrapping_add(self.tweak[(s + 1) % 3]);
+                subkey[$words - 1] = subkey[$words - 1].wrapping_add(s as u64);
+                subkey
+            }
+
+            fn encrypt_words(&self, v: &mut [u64; $words]) {
+                for d in 0..$rounds {
+                    if d % 4 == 0 {
+                        for (word, key) in v.iter_mut().zip(self.subkey(d / 4).iter()) {
+                            *word = word.wrapping_add(*key);
+                        }
+                    }
+
+                    for (j, &rotation) in Self::ROTATIONS[d % 8].iter().enumerate() {
+                        v[2 * j] = v[2 * j].wrapping_add(v[2 * j + 1]);
+                        v[2 * j + 1] = v[2 * j + 1].rotate_left(rotation) ^ v[2 * j];
+                    }
+
+                    let f = *v;
+                    for (word, &i) in v.iter_mut().zip(Self::PERMUTATION.iter()) {
+                        *word = f[i];
+                    }
+                }
+
+                for (word, key) in v.iter_mut().zip(self.subkey($rounds / 4).iter()) {
+                    *word = word.wrapping_add(*key);
+                }
+            }
+
+            fn decrypt_words(&self, v: &mut [u64; $words]) {
+                for (word, key) in v.iter_mut().zip(self.subkey($rounds / 4).iter()) {
+                    *word = word.wrapping_sub(*key);
+                }
+
+                for d in (0..$rounds).rev() {
+                    let f = *v;
+                    for (word, &i) in f.iter().zip(Self::PERMUTATION.iter()) {
+                        v[i] = *word;
+                    }
+
+                    for (j, &rotation) in Self::ROTATIONS[d % 8].iter().enumerate() {
+                        v[2 * j + 1] = (v[2 * j + 1] ^ v[2 * j]).rotate_right(rotation);
+                        v[2 * j] = v[2 * j].wrapping_sub(v[2 * j + 1]);
+                    }
+
+                    if d % 4 == 0 {
+                        for (word, key) in v.iter_mut().zip(self.subkey(d / 4).iter()) {
+                            *word = word.wrapping_sub(*key);
+                        }
+                    }
+                }
+            }
+
+            pub fn encrypt_block(&self, block: &mut [u8; 8 * $words]) {
+                let mut v = [0u64; $words];
+                for (word, bytes) in v.iter_mut().zip(block.chunks(8)) {
+                    *word = u64::from_le_bytes(bytes.try_into().unwrap());
+                }
+                self.encrypt_words(&mut v);
+                for (bytes, word) in block.chunks_mut(8).zip(v.iter()) {
+                    bytes.copy_from_slice(&word.to_le_bytes());
+                }
+            }
+
+            pub fn decrypt_block(&self, block: &mut [u8; 8 * $words]) {
+                let mut v = [0u64; $words];
+                for (word, bytes) in v.iter_mut().zip(block.chunks(8)) {
+                    *word = u64::from_le_bytes(bytes.try_into().unwrap());
+                }
+                self.decrypt_words(&mut v);
+                for (bytes, word) in block.chunks_mut(8).zip(v.iter()) {
+                    bytes.copy_from_slice(&word.to_le_bytes());
+                }
+            }
+        }
+    };
+}
+
+threefish_impl!(
+    Threefish256,
+    4,
+    72,
+    [
+        [14, 16],
+        [52, 57],
+        [23, 40],
+        [5, 37],
+        [25, 33],
+        [46, 12],
+        [58, 22],
+        [32, 32],
+    ],
+    [0, 3, 2, 1]
+);
+
+threefish_impl!(
+    Threefish512,
+    8,
+    72,
+    [
+        [46, 36, 19, 37],
+        [33, 27, 14, 42],
+        [17, 49, 36, 39],
+        [44, 9, 54, 56],
+        [39, 30, 34, 24],
+        [13, 50, 10, 17],
+        [25, 29, 39, 43],
+        [8, 35, 56, 22],
+    ],
+    [2, 1, 4, 7, 6, 5, 0, 3]
+);
+
+threefish_impl!(
+    Threefish1024,
+    16,
+    80,
+    [
+        [24, 13, 8, 47, 8, 17, 22, 37],
+        [38, 19, 10, 55, 49, 18, 23, 52],
+        [33, 4, 51, 13, 34, 41, 59, 17],
+        [5, 20, 48, 41, 47, 28, 16, 25],
+        [41, 9, 37, 31, 12, 47, 44, 30],
+        [16, 34, 56, 51, 4, 53, 42, 41],
+        [31, 44, 47, 46, 19, 42, 44, 25],
+        [9, 48, 35, 52, 23, 31, 37, 20],
+    ],
+    [0, 9, 2, 13, 6, 11, 4, 15, 10, 7, 12, 3, 14, 5, 8, 1]
+);
+
+macro_rules! skein_impl {
+    ($name:ident, $threefish:ident, $words:literal) => {
+        #[derive(Clone)]
+        pub struct $name<const LEN: usize> {
+            iv: [u64; $words],
+            state: [u64; $words],
+            position: u128,
+            block: [u8; 8 * $words],
+            offset: usize,
+        }
+
+        impl<const LEN: usize> $name<LEN> {
+            pub fn new_keyed(key: &[u8]) -> Self {
+                assert!(LEN > 0);
+
+                let state = if key.is_empty() {
+                    [0u64; $words]
+                } else {
+                    Self::ubi_bytes(&[0u64; $words], key, TYPE_KEY)
+                };
+
+                let mut config = [0u8; 32];
+                config[0..4].copy_from_slice(b"SHA3");
+                config[4..6].copy_from_slice(&1u16.to_le_bytes());
+                config[8..16].copy_from_slice(&(8 * LEN as u64).to_le_bytes());
+                let iv = Self::ubi_bytes(&state, &config, TYPE_CONFIG);
+
+                Self {
+                    iv,
+                    state: iv,
+                    position: 0,
+                    block: [0u8; 8 * $words],
+                    offset: 0,
+                }
+            }
+
+            fn ubi(
+                state: &[u64; $words],
+                block: &[u8; 8 * $words],
+                position: u128,
+                flags: u64,
+            ) -> [u64; $words] {
+                let mut m = [0u64; $words];
+                for (word, bytes) in m.iter_mut().zip(block.chunks(8)) {
+                    *word = u64::from_le_bytes(bytes.try_into().unwrap());
+                }
+
+                let tweak = [position as u64, (position >> 64) as u64 | flags];
+                let mut v = m;
+                $threefish::from_words(state, tweak).encrypt_words(&mut v);
+                for (word, m) in v.iter_mut().zip(m.iter()) {
+                    *word ^= m;
+                }
+                v
+            }
+
+            fn ubi_bytes(state: &[u64; $words], bytes: &[u8], block_type: u64) -> [u64; $words] {
+                let blocks = bytes.len().div_ceil(8 * $words).max(1);
+                let mut state = *state;
+                let mut position = 0;
+
+                for i in 0..blocks {
+                    let chunk = &bytes[(8 * $words * i).min(bytes.len())
+                        ..(8 * $words * (i + 1)).min(bytes.len())];
+                    let mut block = [0u8; 8 * $words];
+                    block[0..chunk.len()].copy_from_slice(chunk);
+                    position += chunk.len() as u128;
+
+                    let mut flags = block_type << 56;
+                    if i == 0 {
+                        flags |= FIRST;
+                    }
+                    if i == blocks - 1 {
+                        flags |= FINAL;
+                    }
+                    state = Self::ubi(&state, &block, position, flags);
+                }
+
+                state
+            }
+
+            fn compress(&mut self, last: bool) {
+                let mut flags = TYPE_MESSAGE << 56;
+                if self.position == 0 {
+                    flags |= FIRST;
+                }
+                if last {
+                    flags |= FINAL;
+                }
+                self.position += self.offset as u128;
+                self.state = Self::ubi(&self.state, &self.block, self.position, flags);
+            }
+
+            fn reset(&mut self) {
+                self.state = self.iv;
+                self.position = 0;
+                self.block = [0u8; 8 * $words];
+                self.offset = 0;
+            }
+        }
+
+        impl<const LEN: usize> Digest<[u8; LEN]> for $name<LEN> {
+            const BLOCK_SIZE: usize = 8 * $words;
+            const OUTPUT_SIZE: usize = LEN;
+
+            fn new() -> Self {
+                Self::new_keyed(&[])
+            }
+
+            fn hash(bytes: impl AsRef<[u8]>) -> [u8; LEN] {
+                Self::new().chain(bytes).finalize()
+            }
+
+            fn update(&mut self, bytes: impl AsRef<[u8]>) {
+                let mut bytes = bytes.as_ref();
+
+                while !bytes.is_empty() {
+                    if self.offset == 8 * $words {
+                        self.compress(false);
+                        self.offset = 0;
+                    }
+
+                    let len = (8 * $words - self.offset).min(bytes.len());
+                    self.block[self.offset..self.offset + len].copy_from_slice(&bytes[0..len]);
+                    self.offset += len;
+                    bytes = &bytes[len..];
+                }
+            }
+
+            fn chain(mut self, bytes: impl AsRef<[u8]>) -> Self {
+                self.update(bytes);
+                self
+            }
+
+            fn finalize(mut self) -> [u8; LEN] {
+                self.finalize_reset()
+            }
+
+            fn finalize_reset(&mut self) -> [u8; LEN] {
+                self.block[self.offset..].fill(0);
+                self.compress(true);
+
+                let mut bytes = [0u8; LEN];
+                for (i, chunk) in bytes.chunks_mut(8 * $words).enumerate() {
+                    let output =
+                        Self::ubi_bytes(&self.state, &(i as u64).to_le_bytes(), TYPE_OUTPUT);
+                    for (bytes, word) in chunk.chunks_mut(8).zip(output.iter()) {
+                        bytes.copy_from_slice(&word.to_le_bytes()[0..bytes.len()]);
+                    }
+                }
+
+                self.reset();
+                bytes
+            }
+        }
+    };
+}
+
+skein_impl!(Skein256, Threefish256, 4);
+skein_impl!(Skein512, Threefish512, 8);
+skein_impl!(Skein1024, Threefish1024, 16);
+
+pub type Skein512x256Digest = Skein512<32>;
+pub type Skein512x512Digest = Skein512<64>;
+pub type Skein1024x1024Digest = Skein1024<128>;
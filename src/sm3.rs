@@ -0,0 +1,146 @@
+use crate::digest::{Digest512, HashState, Schedule512};
+
+#[derive(Clone)]
+pub struct SM3State(u32, u32, u32, u32, u32, u32, u32, u32);
+
+type SM3Function = fn(u32, u32, u32) -> u32;
+
+#[allow(clippy::many_single_char_names)]
+impl HashState<[u8; 32], (SM3Function, SM3Function, u32, u32, u32)> for SM3State {
+    fn new() -> SM3State {
+        let a = 0x7380166fu32;
+        let b = 0x4914b2b9u32;
+        let c = 0x172442d7u32;
+        let d = 0xda8a0600u32;
+        let e = 0xa96f30bcu32;
+        let f = 0x163138aau32;
+        let g = 0xe38dee4du32;
+        let h = 0xb0fb0e4eu32;
+        SM3State(a, b, c, d, e, f, g, h)
+    }
+
+    fn step(&self, (ff, gg, t, w, w_prime): (SM3Function, SM3Function, u32, u32, u32)) -> SM3State {
+        let SM3State(a, b, c, d, e, f, g, h) = *self;
+
+        fn p0(x: u32) -> u32 {
+            x ^ x.rotate_left(9) ^ x.rotate_left(17)
+        }
+
+        let ss1 = a
+            .rotate_left(12)
+            .wrapping_add(e)
+            .wrapping_add(t)
+            .rotate_left(7);
+        let ss2 = ss1 ^ a.rotate_left(12);
+        let tt1 = ff(a, b, c)
+            .wrapping_add(d)
+            .wrapping_add(ss2)
+            .wrapping_add(w_prime);
+        let tt2 = gg(e, f, g)
+            .wrapping_add(h)
+            .wrapping_add(ss1)
+            .wrapping_add(w);
+        SM3State(
+            tt1,
+            a,
+            b.rotate_left(9),
+            c,
+            p0(tt2),
+            e,
+            f.rotate_left(19),
+            g,
+        )
+    }
+
+    fn merge(&self, other: &SM3State) -> SM3State {
+        let SM3State(a, b, c, d, e, f, g, h) = *self;
+        let SM3State(a_o, b_o, c_o, d_o, e_o, f_o, g_o, h_o) = *other;
+        SM3State(
+            a ^ a_o,
+            b ^ b_o,
+            c ^ c_o,
+            d ^ d_o,
+            e ^ e_o,
+            f ^ f_o,
+            g ^ g_o,
+            h ^ h_o,
+        )
+    }
+
+    fn to_bytes(&self) -> [u8; 32] {
+        let mut bytes = [0; 32];
+        let SM3State(a, b, c, d, e, f, g, h) = self;
+        for (i, x) in [a, b, c, d, e, f, g, h].iter().enumerate() {
+            bytes[4 * i..4 * (i + 1)].copy_from_slice(&x.to_be_bytes())
+        }
+        bytes
+    }
+}
+
+pub struct SM3Schedule {
+    w: [u32; 68],
+    t: usize,
+}
+
+impl Schedule512 for SM3Schedule {
+    fn new(block: &[u8; 64]) -> SM3Schedule {
+        fn p1(x: u32) -> u32 {
+            x ^ x.rotate_left(15) ^ x.rotate_left(23)
+        }
+
+        let mut w = [0u32; 68];
+
+        for (i, w) in w[0..16].iter_mut().enumerate() {
+            let base = 4 * i;
+            *w = u32::from_be_bytes([
+                block[base],
+                block[base + 1],
+                block[base + 2],
+                block[base + 3],
+            ]);
+        }
+
+        for i in 16..68 {
+            w[i] = p1(w[i - 16] ^ w[i - 9] ^ w[i - 3].rotate_left(15))
+                ^ w[i - 13].rotate_left(7)
+                ^ w[i - 6];
+        }
+
+        SM3Schedule { w, t: 0 }
+    }
+}
+
+impl Iterator for SM3Schedule {
+    type Item = (SM3Function, SM3Function, u32, u32, u32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        fn parity(x: u32, y: u32, z: u32) -> u32 {
+            x ^ y ^ z
+        }
+
+        fn maj(x: u32, y: u32, z: u32) -> u32 {
+            (x & y) | (x & z) | (y & z)
+        }
+
+        fn ch(x: u32, y: u32, z: u32) -> u32 {
+            (x & y) | (!x & z)
+        }
+
+        if self.t < 64 {
+            let (ff, gg, t) = if self.t < 16 {
+                (parity as SM3Function, parity as SM3Function, 0x79cc4519u32)
+            } else {
+                (maj as SM3Function, ch as SM3Function, 0x7a879d8au32)
+            };
+            let w = self.w[self.t];
+            let w_prime = w ^ self.w[self.t + 4];
+            let t = t.rotate_left(self.t as u32 % 32);
+            self.t += 1;
+            Some((ff, gg, t, w, w_prime))
+        } else {
+            None
+        }
+    }
+}
+
+pub type SM3Digest = Digest512<[u8; 32], SM3Schedule, SM3State>;
@@ -0,0 +1,176 @@
+use crate::digest::{Digest, Xof, XofReader};
+use crate::keccak::keccak_p1600;
+use core::mem::replace;
+
+#[derive(Clone)]
+pub struct Sponge<const RATE: usize, const ROUNDS: usize = 24> {
+    state: [u64; 25],
+    offset: usize,
+}
+
+impl<const RATE: usize, const ROUNDS: usize> Sponge<RATE, ROUNDS> {
+    pub fn new() -> Self {
+        Self {
+            state: [0u64; 25],
+            offset: 0,
+        }
+    }
+
+    pub fn absorb(&mut self, bytes: &[u8]) {
+        let mut bytes = bytes;
+
+        while !bytes.is_empty() {
+            if self.offset == 0 && bytes.len() >= RATE {
+                for (lane, word) in self.state.iter_mut().zip(bytes[0..RATE].chunks(8)) {
+                    *lane ^= u64::from_le_bytes([
+                        word[0], word[1], word[2], word[3], word[4], word[5], word[6], word[7],
+                    ]);
+                }
+                keccak_p1600(&mut self.state, ROUNDS);
+                bytes = &bytes[RATE..];
+            } else {
+                self.xor_byte(bytes[0]);
+                self.offset += 1;
+                if self.offset == RATE {
+                    keccak_p1600(&mut self.state, ROUNDS);
+                    self.offset = 0;
+                }
+                bytes = &bytes[1..];
+            }
+        }
+    }
+
+    pub fn pad(&mut self, domain: u8) {
+        self.xor_byte(domain);
+        self.offset = RATE - 1;
+        self.xor_byte(0x80u8);
+        keccak_p1600(&mut self.state, ROUNDS);
+        self.offset = 0;
+    }
+
+    pub fn fill_block(&mut self) {
+        if self.offset != 0 {
+            keccak_p1600(&mut self.state, ROUNDS);
+            self.offset = 0;
+        }
+    }
+
+    pub fn squeeze(&mut self, bytes: &mut [u8]) {
+        for byte in bytes.iter_mut() {
+            if self.offset == RATE {
+                keccak_p1600(&mut self.state, ROUNDS);
+                self.offset = 0;
+            }
+            *byte = (self.state[self.offset / 8] >> (8 * (self.offset % 8))) as u8;
+            self.offset += 1;
+        }
+    }
+
+    fn xor_byte(&mut self, byte: u8) {
+        self.state[self.offset / 8] ^= (byte as u64) << (8 * (self.offset % 8));
+    }
+}
+
+#[derive(Clone)]
+pub struct SpongeDigest<const RATE: usize, const DOMAIN: u8, const LEN: usize> {
+    sponge: Sponge<RATE>,
+}
+
+impl<const RATE: usize, const DOMAIN: u8, const LEN: usize> Digest<[u8; LEN]>
+    for SpongeDigest<RATE, DOMAIN, LEN>
+{
+    const BLOCK_SIZE: usize = RATE;
+    const OUTPUT_SIZE: usize = LEN;
+
+    fn new() -> Self {
+        Self {
+            sponge: Sponge::new(),
+        }
+    }
+
+    fn hash(bytes: impl AsRef<[u8]>) -> [u8; LEN] {
+        Self::new().chain(bytes).finalize()
+    }
+
+    fn update(&mut self, bytes: impl AsRef<[u8]>) {
+        self.sponge.absorb(bytes.as_ref());
+    }
+
+    fn chain(mut self, bytes: impl AsRef<[u8]>) -> Self {
+        self.update(bytes);
+        self
+    }
+
+    fn finalize(mut self) -> [u8; LEN] {
+        self.finalize_reset()
+    }
+
+    fn finalize_reset(&mut self) -> [u8; LEN] {
+        let mut bytes = [0u8; LEN];
+        self.sponge.pad(DOMAIN);
+        self.sponge.squeeze(&mut bytes);
+        self.sponge = Sponge::new();
+        bytes
+    }
+}
+
+#[derive(Clone)]
+pub struct SpongeXof<const RATE: usize, const DOMAIN: u8, const ROUNDS: usize = 24> {
+    sponge: Sponge<RATE, ROUNDS>,
+}
+
+impl<const RATE: usize, const DOMAIN: u8, const ROUNDS: usize> SpongeXof<RATE, DOMAIN, ROUNDS> {
+    const PADDING: u8 = {
+        assert!(DOMAIN >= 0x01 && DOMAIN <= 0x7f);
+        DOMAIN
+    };
+}
+
+impl<const RATE: usize, const DOMAIN: u8, const ROUNDS: usize> Xof<SpongeReader<RATE, ROUNDS>>
+    for SpongeXof<RATE, DOMAIN, ROUNDS>
+{
+    fn new() -> Self {
+        Self {
+            sponge: Sponge::new(),
+        }
+    }
+
+    fn hash(bytes: impl AsRef<[u8]>) -> SpongeReader<RATE, ROUNDS> {
+        Self::new().chain(bytes).finalize()
+    }
+
+    fn update(&mut self, bytes: impl AsRef<[u8]>) {
+        self.sponge.absorb(bytes.as_ref());
+    }
+
+    fn chain(mut self, bytes: impl AsRef<[u8]>) -> Self {
+        self.update(bytes);
+        self
+    }
+
+    fn finalize(self) -> SpongeReader<RATE, ROUNDS> {
+        SpongeReader::new(self.sponge, Self::PADDING)
+    }
+
+    fn finalize_reset(&mut self) -> SpongeReader<RATE, ROUNDS> {
+        SpongeReader::new(replace(&mut self.sponge, Sponge::new()), Self::PADDING)
+    }
+}
+
+#[derive(Clone)]
+pub struct SpongeReader<const RATE: usize, const ROUNDS: usize = 24> {
+    sponge: Sponge<RATE, ROUNDS>,
+}
+
+impl<const RATE: usize, const ROUNDS: usize> SpongeReader<RATE, ROUNDS> {
+    pub fn new(mut sponge: Sponge<RATE, ROUNDS>, domain: u8) -> Self {
+        sponge.pad(domain);
+        Self { sponge }
+    }
+}
+
+impl<const RATE: usize, const ROUNDS: usize> XofReader for SpongeReader<RATE, ROUNDS> {
+    fn read(&mut self, bytes: &mut [u8]) {
+        self.sponge.squeeze(bytes);
+    }
+}
@@ -1,8 +1,28 @@
 #[cfg(test)]
+#[allow(clippy::module_inception)]
 mod test {
+    use crate::argon2::{Argon2, Argon2Error, Variant};
+    use crate::eip55;
+    use crate::hkdf;
+    use crate::pbkdf2::{self, Pbkdf2Error};
+    use crate::phc::{self, PhcError, PhcString};
+    use crate::scrypt::{Scrypt, ScryptError};
+    use crate::sha_crypt::{self, ShaCryptError};
+    use crate::tth;
     use crate::{
-        Digest, SHA1Digest, SHA224Digest, SHA256Digest, SHA384Digest, SHA512Digest,
-        SHA512x224Digest, SHA512x256Digest,
+        AsconCXof128, AsconHash256Digest, AsconReader, AsconXof128, BitDigest, Blake2b,
+        Blake2b512Digest, Blake2bParams, Blake2s, Blake2s256Digest, Blake2sParams, Blake3Digest,
+        CRC32CDigest, CSHAKE128Xof, CSHAKE256Xof, Digest, FNV1a64Digest, Hmac, Keccak256Digest,
+        Keccak512Digest, MD4Digest, MD5Digest, MidstateDigest, ParallelHash128, ParallelHash256,
+        RIPEMD128Digest, RIPEMD160Digest, RIPEMD256Digest, RIPEMD320Digest, SHA1Collision,
+        SHA1DCDigest, SHA1Digest, SHA224Digest, SHA256Digest, SHA384Digest, SHA3_224Digest,
+        SHA3_256Digest, SHA3_384Digest, SHA3_512Digest, SHA512Digest, SHA512tDigest,
+        SHA512x224Digest, SHA512x256Digest, SHAKE128Xof, SHAKE256Xof, SM3Digest,
+        Skein1024x1024Digest, Skein256, Skein512, Skein512x256Digest, Skein512x512Digest,
+        Streebog256Digest, Streebog512Digest, Threefish1024, Threefish256, Threefish512,
+        TigerDigest, TupleHash128, TupleHash256, TurboSHAKE128Xof, TurboSHAKE256Xof,
+        WhirlpoolDigest, XXH3x128Digest, XXH3x64Digest, XXH64Digest, Xof, XofReader, KMAC128,
+        KMAC256, KT128, KT256,
     };
 
     use rand::RngCore;
@@ -20,12 +40,12 @@ mod test {
     ];
 
     fn hex_str_to_bytes(hex_str: &str) -> Vec<u8> {
-        assert!(hex_str.len() % 2 == 0);
+        assert!(hex_str.len().is_multiple_of(2));
 
         fn hex_digit_to_byte(digit: u8) -> u8 {
-            if 0x30 <= digit && digit <= 0x39 {
+            if (0x30..=0x39).contains(&digit) {
                 digit - 0x30
-            } else if 0x61 <= digit && digit <= 0x66 {
+            } else if (0x61..=0x66).contains(&digit) {
                 10 + digit - 0x61
             } else {
                 panic!()
@@ -42,6 +62,18 @@ mod test {
             .collect::<Vec<u8>>()
     }
 
+    fn dice_bytes(bytes: &[u8]) -> Vec<&[u8]> {
+        if bytes.is_empty() {
+            return vec![];
+        }
+
+        let mut rng = rand::thread_rng();
+        let index = bytes.len() - 1 - (rng.next_u64() as usize) % bytes.len();
+        let mut diced = dice_bytes(&bytes[0..index]);
+        diced.push(&bytes[index..]);
+        diced
+    }
+
     fn check_digest<Res: Into<Vec<u8>>, D: Digest<Res>>(bytes: &[u8], expectation: &[u8]) {
         assert_eq!(D::hash(bytes).into(), expectation);
         assert_eq!(D::new().chain(bytes).finalize().into(), expectation);
@@ -56,18 +88,6 @@ mod test {
         d2.update(bytes);
         assert_eq!(d2.finalize_reset().into(), expectation);
 
-        fn dice_bytes(bytes: &[u8]) -> Vec<&[u8]> {
-            if bytes.is_empty() {
-                return vec![];
-            }
-
-            let mut rng = rand::thread_rng();
-            let index = bytes.len() - 1 - (rng.next_u64() as usize) % bytes.len();
-            let mut diced = dice_bytes(&bytes[0..index]);
-            diced.push(&bytes[index..]);
-            diced
-        }
-
         let diced = dice_bytes(bytes);
 
         let mut d3 = D::new();
@@ -80,6 +100,47 @@ mod test {
         assert_eq!(d4.finalize().into(), expectation);
     }
 
+    fn check_xof<Reader: XofReader, X: Xof<Reader>>(
+        bytes: &[u8],
+        offset: usize,
+        expectation: &[u8],
+    ) {
+        fn squeeze(mut reader: impl XofReader, len: usize) -> Vec<u8> {
+            let mut rng = rand::thread_rng();
+            let mut output = vec![0u8; len];
+            let mut pos = 0;
+            while pos < len {
+                let n = 1 + (rng.next_u64() as usize) % (len - pos);
+                reader.read(&mut output[pos..pos + n]);
+                pos += n;
+            }
+            output
+        }
+
+        let len = offset + expectation.len();
+
+        let mut whole = vec![0u8; len];
+        X::hash(bytes).read(&mut whole);
+        assert_eq!(&whole[offset..], expectation);
+
+        assert_eq!(&squeeze(X::hash(bytes), len)[offset..], expectation);
+        assert_eq!(
+            &squeeze(X::new().chain(bytes).finalize(), len)[offset..],
+            expectation
+        );
+
+        let mut x1 = X::new();
+        x1.update(bytes);
+        assert_eq!(&squeeze(x1.finalize_reset(), len)[offset..], expectation);
+        x1.update(bytes);
+        assert_eq!(&squeeze(x1.finalize_reset(), len)[offset..], expectation);
+
+        let x2 = dice_bytes(bytes)
+            .iter()
+            .fold(X::new(), |x, seg| x.chain(seg));
+        assert_eq!(&squeeze(x2.finalize(), len)[offset..], expectation);
+    }
+
     #[test]
     fn words() {
         let file = File::open("gen/data/word-hashes.list").unwrap();
@@ -126,4 +187,3061 @@ mod test {
             check_digest::<[u8; 32], SHA512x256Digest>(bytes, &hex_str_to_bytes(case[7]));
         }
     }
+
+    #[test]
+    fn sha3() {
+        let cases: [(&[u8], [&str; 4]); 5] = [
+            (
+                b"",
+                [
+                    "6b4e03423667dbb73b6e15454f0eb1abd4597f9a1b078e3f5b5a6bc7",
+                    "a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a",
+                    "0c63a75b845e4f7d01107d852e4c2485c51a50aaaa94fc61995e71bbee983a2ac3713831264adb47fb6bd1e058d5f004",
+                    "a69f73cca23a9ac5c8b567dc185a756e97c982164fe25859e0d1dcc1475c80a615b2123af1f5f94c11e3e9402c3ac558f500199d95b6d3e301758586281dcd26",
+                ],
+            ),
+            (
+                b"abc",
+                [
+                    "e642824c3f8cf24ad09234ee7d3c766fc9a3a5168d0c94ad73b46fdf",
+                    "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532",
+                    "ec01498288516fc926459f58e2c6ad8df9b473cb0fc08c2596da7cf0e49be4b298d88cea927ac7f539f1edf228376d25",
+                    "b751850b1a57168a5693cd924b6b096e08f621827444f70d884f5d0240d2712e10e116e9192af3c91a7ec57647e3934057340b4cf408d5a56592f8274eec53f0",
+                ],
+            ),
+            (
+                b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+                [
+                    "8a24108b154ada21c9fd5574494479ba5c7e7ab76ef264ead0fcce33",
+                    "41c0dba2a9d6240849100376a8235e2c82e1b9998a999e21db32dd97496d3376",
+                    "991c665755eb3a4b6bbdfb75c78a492e8c56a22c5c4d7e429bfdbc32b9d4ad5aa04a1f076e62fea19eef51acd0657c22",
+                    "04a371e84ecfb5b8b77cb48610fca8182dd457ce6f326a0fd3d7ec2f1e91636dee691fbe0c985302ba1b0d8dc78c086346b533b49c030d99a27daf1139d6e75e",
+                ],
+            ),
+            (
+                b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu",
+                [
+                    "543e6868e1666c1a643630df77367ae5a62a85070a51c14cbf665cbc",
+                    "916f6061fe879741ca6469b43971dfdb28b1a32dc36cb3254e812be27aad1d18",
+                    "79407d3b5916b59c3e30b09822974791c313fb9ecc849e406f23592d04f625dc8c709b98b43b3852b337216179aa7fc7",
+                    "afebb2ef542e6579c50cad06d2e578f9f8dd6881d7dc824d26360feebf18a4fa73e3261122948efcfd492e74e82e2189ed0fb440d187f382270cb455f21dd185",
+                ],
+            ),
+            (
+                &[0xa3u8; 200],
+                [
+                    "9376816aba503f72f96ce7eb65ac095deee3be4bf9bbc2a1cb7e11e0",
+                    "79f38adec5c20307a98ef76e8324afbfd46cfd81b22e3973c65fa1bd9de31787",
+                    "1881de2ca7e41ef95dc4732b8f5f002b189cc1e42b74168ed1732649ce1dbcdd76197a31fd55ee989f2d7050dd473e8f",
+                    "e76dfad22084a8b1467fcf2ffa58361bec7628edf5f3fdc0e4805dc48caeeca81b7c13c30adf52a3659584739a2df46be589c51ca1a4a8416df6545a1ce8ba00",
+                ],
+            ),
+        ];
+
+        for (bytes, expectations) in cases.iter() {
+            check_digest::<[u8; 28], SHA3_224Digest>(bytes, &hex_str_to_bytes(expectations[0]));
+            check_digest::<[u8; 32], SHA3_256Digest>(bytes, &hex_str_to_bytes(expectations[1]));
+            check_digest::<[u8; 48], SHA3_384Digest>(bytes, &hex_str_to_bytes(expectations[2]));
+            check_digest::<[u8; 64], SHA3_512Digest>(bytes, &hex_str_to_bytes(expectations[3]));
+        }
+    }
+
+    #[test]
+    fn shake() {
+        let cases: [(&[u8], [&str; 4]); 3] = [
+            (
+                b"",
+                [
+                    "7f9c2ba4e88f827d616045507605853ed73b8093f6efbc88eb1a6eacfa66ef26",
+                    "3a7a9c4a95d91c55d495e9f51dd0b5e9d83c6d5e8ce803aa62b8d654db53d09b",
+                    "46b9dd2b0ba88d13233b3feb743eeb243fcd52ea62b81b82b50c27646ed5762fd75dc4ddd8c0f200cb05019d67b592f6fc821c49479ab48640292eacb3b7c4be",
+                    "29d310912f729ec6cfa36c6ac6a75837143045d791cc85eff5b21932f23861bc",
+                ],
+            ),
+            (
+                b"abc",
+                [
+                    "5881092dd818bf5cf8a3ddb793fbcba74097d5c526a6d35f97b83351940f2cc8",
+                    "35d6dbb75651b284076f5fde47b4a0586ee173e30bd4d08f2bc59c6114bdd745",
+                    "483366601360a8771c6863080cc4114d8db44530f8f1e1ee4f94ea37e78b5739d5a15bef186a5386c75744c0527e1faa9f8726e462a12a4feb06bd8801e751e4",
+                    "f2abbd26edad1553ea3a626f359e8f79ade16384e151755c47e822fc74c5d710",
+                ],
+            ),
+            (
+                &[0xa3u8; 200],
+                [
+                    "131ab8d2b594946b9c81333f9bb6e0ce75c3b93104fa3469d3917457385da037",
+                    "b744c8506f37e9b4e749a184b30f43eb188d855f1b70d71ff3e50c537ac1b0f8",
+                    "cd8a920ed141aa0407a22d59288652e9d9f1a7ee0c1e7c1ca699424da84a904d2d700caae7396ece96604440577da4f3aa22aeb8857f961c4cd8e06f0ae6610b",
+                    "cc5d9ac36a6df622a070d43fed781f5f149f7b62675e7d1a4d6dec48c1c71645",
+                ],
+            ),
+        ];
+
+        for (bytes, expectations) in cases.iter() {
+            check_xof::<_, SHAKE128Xof>(bytes, 0, &hex_str_to_bytes(expectations[0]));
+            check_xof::<_, SHAKE128Xof>(bytes, 368, &hex_str_to_bytes(expectations[1]));
+            check_xof::<_, SHAKE256Xof>(bytes, 0, &hex_str_to_bytes(expectations[2]));
+            check_xof::<_, SHAKE256Xof>(bytes, 368, &hex_str_to_bytes(expectations[3]));
+        }
+    }
+
+    #[test]
+    fn cshake() {
+        let short = [0x00u8, 0x01, 0x02, 0x03];
+        let long = (0x00u8..0xc8).collect::<Vec<u8>>();
+
+        let cases: [(&[u8], &str); 2] = [
+            (
+                &short,
+                "c1c36925b6409a04f1b504fcbca9d82b4017277cb5ed2b2065fc1d3814d5aaf5",
+            ),
+            (
+                &long,
+                "c5221d50e4f822d96a2e8881a961420f294b7b24fe3d2094baed2c6524cc166b",
+            ),
+        ];
+
+        for (bytes, expectation) in cases.iter() {
+            let mut output = [0u8; 32];
+            let mut cshake = CSHAKE128Xof::new(b"", b"Email Signature");
+            cshake.update(bytes);
+            cshake.finalize_reset().read(&mut output);
+            assert_eq!(&output[..], &hex_str_to_bytes(expectation)[..]);
+
+            let mut output = [0u8; 32];
+            let diced = dice_bytes(bytes);
+            let cshake = diced.iter().fold(cshake, |cshake, seg| cshake.chain(seg));
+            cshake.finalize().read(&mut output);
+            assert_eq!(&output[..], &hex_str_to_bytes(expectation)[..]);
+        }
+
+        let cases: [(&[u8], &str); 2] = [
+            (
+                &short,
+                "d008828e2b80ac9d2218ffee1d070c48b8e4c87bff32c9699d5b6896eee0edd164020e2be0560858d9c00c037e34a96937c561a74c412bb4c746469527281c8c",
+            ),
+            (
+                &long,
+                "07dc27b11e51fbac75bc7b3c1d983e8b4b85fb1defaf218912ac86430273091727f42b17ed1df63e8ec118f04b23633c1dfb1574c8fb55cb45da8e25afb092bb",
+            ),
+        ];
+
+        for (bytes, expectation) in cases.iter() {
+            let mut output = [0u8; 64];
+            let cshake = CSHAKE256Xof::new(b"", b"Email Signature").chain(bytes);
+            cshake.finalize().read(&mut output);
+            assert_eq!(&output[..], &hex_str_to_bytes(expectation)[..]);
+        }
+
+        let mut shake = [0u8; 32];
+        let mut cshake = [0u8; 32];
+        SHAKE128Xof::hash(b"abc").read(&mut shake);
+        CSHAKE128Xof::new(b"", b"")
+            .chain(b"abc")
+            .finalize()
+            .read(&mut cshake);
+        assert_eq!(shake, cshake);
+    }
+
+    #[test]
+    fn kmac() {
+        let key = (0x40u8..0x60).collect::<Vec<u8>>();
+        let short = [0x00u8, 0x01, 0x02, 0x03];
+        let long = (0x00u8..0xc8).collect::<Vec<u8>>();
+
+        let cases: [(&[u8], &[u8], &str, &str); 3] = [
+            (
+                &short,
+                b"",
+                "e5780b0d3ea6f7d3a429c5706aa43a00fadbd7d49628839e3187243f456ee14e",
+                "cd83740bbd92ccc8cf032b1481a0f4460e7ca9dd12b08a0c4031178bacd6ec35",
+            ),
+            (
+                &short,
+                b"My Tagged Application",
+                "3b1fba963cd8b0b59e8c1a6d71888b7143651af8ba0a7070c0979e2811324aa5",
+                "31a44527b4ed9f5c6101d11de6d26f0620aa5c341def41299657fe9df1a3b16c",
+            ),
+            (
+                &long,
+                b"My Tagged Application",
+                "1f5b4e6cca02209e0dcb5ca635b89a15e271ecc760071dfd805faa38f9729230",
+                "47026c7cd793084aa0283c253ef658490c0db61438b8326fe9bddf281b83ae0f",
+            ),
+        ];
+
+        for (bytes, customization, expectation, xof_expectation) in cases.iter() {
+            let mut kmac = KMAC128::new(&key, customization);
+            let mut output = [0u8; 32];
+            kmac.update(bytes);
+            kmac.finalize_reset(&mut output);
+            assert_eq!(&output[..], &hex_str_to_bytes(expectation)[..]);
+
+            kmac.update(bytes);
+            kmac.finalize_xof_reset().read(&mut output);
+            assert_eq!(&output[..], &hex_str_to_bytes(xof_expectation)[..]);
+
+            let kmac = dice_bytes(bytes)
+                .iter()
+                .fold(kmac, |kmac, seg| kmac.chain(seg));
+            kmac.finalize(&mut output);
+            assert_eq!(&output[..], &hex_str_to_bytes(expectation)[..]);
+        }
+
+        let cases: [(&[u8], &[u8], &str, &str); 3] = [
+            (
+                &short,
+                b"My Tagged Application",
+                "20c570c31346f703c9ac36c61c03cb64c3970d0cfc787e9b79599d273a68d2f7f69d4cc3de9d104a351689f27cf6f5951f0103f33f4f24871024d9c27773a8dd",
+                "1755133f1534752aad0748f2c706fb5c784512cab835cd15676b16c0c6647fa96faa7af634a0bf8ff6df39374fa00fad9a39e322a7c92065a64eb1fb0801eb2b",
+            ),
+            (
+                &long,
+                b"",
+                "75358cf39e41494e949707927cee0af20a3ff553904c86b08f21cc414bcfd691589d27cf5e15369cbbff8b9a4c2eb17800855d0235ff635da82533ec6b759b69",
+                "ff7b171f1e8a2b24683eed37830ee797538ba8dc563f6da1e667391a75edc02ca633079f81ce12a25f45615ec89972031d18337331d24ceb8f8ca8e6a19fd98b",
+            ),
+            (
+                &long,
+                b"My Tagged Application",
+                "b58618f71f92e1d56c1b8c55ddd7cd188b97b4ca4d99831eb2699a837da2e4d970fbacfde50033aea585f1a2708510c32d07880801bd182898fe476876fc8965",
+                "d5be731c954ed7732846bb59dbe3a8e30f83e77a4bff4459f2f1c2b4ecebb8ce67ba01c62e8ab8578d2d499bd1bb276768781190020a306a97de281dcc30305d",
+            ),
+        ];
+
+        for (bytes, customization, expectation, xof_expectation) in cases.iter() {
+            let mut output = [0u8; 64];
+            KMAC256::new(&key, customization)
+                .chain(bytes)
+                .finalize(&mut output);
+            assert_eq!(&output[..], &hex_str_to_bytes(expectation)[..]);
+
+            KMAC256::new(&key, customization)
+                .chain(bytes)
+                .finalize_xof()
+                .read(&mut output);
+            assert_eq!(&output[..], &hex_str_to_bytes(xof_expectation)[..]);
+        }
+    }
+
+    #[test]
+    fn tuple_hash() {
+        let elements: [&[u8]; 3] = [
+            &[0x00, 0x01, 0x02],
+            &[0x10, 0x11, 0x12, 0x13, 0x14, 0x15],
+            &[0x20, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27, 0x28],
+        ];
+
+        let cases: [(usize, &[u8], [&str; 4]); 3] = [
+            (
+                2,
+                b"",
+                [
+                    "c5d8786c1afb9b82111ab34b65b2c0048fa64e6d48e263264ce1707d3ffc8ed1",
+                    "2f103cd7c32320353495c68de1a8129245c6325f6f2a3d608d92179c96e68488",
+                    "cfb7058caca5e668f81a12a20a2195ce97a925f1dba3e7449a56f82201ec607311ac2696b1ab5ea2352df1423bde7bd4bb78c9aed1a853c78672f9eb23bbe194",
+                    "03ded4610ed6450a1e3f8bc44951d14fbc384ab0efe57b000df6b6df5aae7cd568e77377daf13f37ec75cf5fc598b6841d51dd207c991cd45d210ba60ac52eb9",
+                ],
+            ),
+            (
+                2,
+                b"My Tuple App",
+                [
+                    "75cdb20ff4db1154e841d758e24160c54bae86eb8c13e7f5f40eb35588e96dfb",
+                    "3fc8ad69453128292859a18b6c67d7ad85f01b32815e22ce839c49ec374e9b9a",
+                    "147c2191d5ed7efd98dbd96d7ab5a11692576f5fe2a5065f3e33de6bba9f3aa1c4e9a068a289c61c95aab30aee1e410b0b607de3620e24a4e3bf9852a1d4367e",
+                    "6483cb3c9952eb20e830af4785851fc597ee3bf93bb7602c0ef6a65d741aeca7e63c3b128981aa05c6d27438c79d2754bb1b7191f125d6620fca12ce658b2442",
+                ],
+            ),
+            (
+                3,
+                b"My Tuple App",
+                [
+                    "e60f202c89a2631eda8d4c588ca5fd07f39e5151998deccf973adb3804bb6e84",
+                    "900fe16cad098d28e74d632ed852f99daab7f7df4d99e775657885b4bf76d6f8",
+                    "45000be63f9b6bfd89f54717670f69a9bc763591a4f05c50d68891a744bcc6e7d6d5b5e82c018da999ed35b0bb49c9678e526abd8e85c13ed254021db9e790ce",
+                    "0c59b11464f2336c34663ed51b2b950bec743610856f36c28d1d088d8a2446284dd09830a6a178dc752376199fae935d86cfdee5913d4922dfd369b66a53c897",
+                ],
+            ),
+        ];
+
+        for (n, customization, expectations) in cases.iter() {
+            let mut tuple_hash = TupleHash128::new(customization);
+            let mut output = [0u8; 32];
+            for element in elements[0..*n].iter() {
+                tuple_hash.update(element);
+            }
+            tuple_hash.finalize_reset(&mut output);
+            assert_eq!(&output[..], &hex_str_to_bytes(expectations[0])[..]);
+
+            let tuple_hash = elements[0..*n]
+                .iter()
+                .fold(tuple_hash, |tuple_hash, element| tuple_hash.chain(element));
+            tuple_hash.finalize_xof().read(&mut output);
+            assert_eq!(&output[..], &hex_str_to_bytes(expectations[1])[..]);
+
+            let mut tuple_hash = TupleHash256::new(customization);
+            let mut output = [0u8; 64];
+            for element in elements[0..*n].iter() {
+                tuple_hash.update(element);
+            }
+            tuple_hash.finalize_reset(&mut output);
+            assert_eq!(&output[..], &hex_str_to_bytes(expectations[2])[..]);
+
+            for element in elements[0..*n].iter() {
+                tuple_hash.update(element);
+            }
+            tuple_hash.finalize_xof_reset().read(&mut output);
+            assert_eq!(&output[..], &hex_str_to_bytes(expectations[3])[..]);
+        }
+
+        let mut split = [0u8; 32];
+        let mut joined = [0u8; 32];
+        TupleHash128::new(b"")
+            .chain(b"ab")
+            .chain(b"c")
+            .finalize(&mut split);
+        TupleHash128::new(b"")
+            .chain(b"a")
+            .chain(b"bc")
+            .finalize(&mut joined);
+        assert_ne!(split, joined);
+    }
+
+    #[test]
+    fn parallel_hash() {
+        let bytes = [
+            0x00u8, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15,
+            0x16, 0x17, 0x20, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27,
+        ];
+
+        let cases: [(&[u8], [&str; 4]); 2] = [
+            (
+                b"",
+                [
+                    "ba8dc1d1d979331d3f813603c67f72609ab5e44b94a0b8f9af46514454a2b4f5",
+                    "fe47d661e49ffe5b7d999922c062356750caf552985b8e8ce6667f2727c3c8d3",
+                    "bc1ef124da34495e948ead207dd9842235da432d2bbc54b4c110e64c451105531b7f2a3e0ce055c02805e7c2de1fb746af97a1dd01f43b824e31b87612410429",
+                    "c10a052722614684144d28474850b410757e3cba87651ba167a5cbddff7f466675fbf84bcae7378ac444be681d729499afca667fb879348bfdda427863c82f1c",
+                ],
+            ),
+            (
+                b"Parallel Data",
+                [
+                    "fc484dcb3f84dceedc353438151bee58157d6efed0445a81f165e495795b7206",
+                    "ea2a793140820f7a128b8eb70a9439f93257c6e6e79b4a540d291d6dae7098d7",
+                    "cdf15289b54f6212b4bc270528b49526006dd9b54e2b6add1ef6900dda3963bb33a72491f236969ca8afaea29c682d47a393c065b38e29fae651a2091c833110",
+                    "538e105f1a22f44ed2f5cc1674fbd40be803d9c99bf5f8d90a2c8193f3fe6ea768e5c1a20987e2c9c65febed03887a51d35624ed12377594b5585541dc377efc",
+                ],
+            ),
+        ];
+
+        for (customization, expectations) in cases.iter() {
+            let mut parallel_hash = ParallelHash128::new(8, customization);
+            let mut output = [0u8; 32];
+            parallel_hash.update(bytes);
+            parallel_hash.finalize_reset(&mut output);
+            assert_eq!(&output[..], &hex_str_to_bytes(expectations[0])[..]);
+
+            let parallel_hash = dice_bytes(&bytes)
+                .iter()
+                .fold(parallel_hash, |parallel_hash, seg| parallel_hash.chain(seg));
+            parallel_hash.finalize_xof().read(&mut output);
+            assert_eq!(&output[..], &hex_str_to_bytes(expectations[1])[..]);
+
+            let mut parallel_hash = ParallelHash256::new(8, customization).threads(3);
+            let mut output = [0u8; 64];
+            parallel_hash.update(bytes);
+            parallel_hash.finalize_reset(&mut output);
+            assert_eq!(&output[..], &hex_str_to_bytes(expectations[2])[..]);
+
+            parallel_hash.update(bytes);
+            parallel_hash.finalize_xof_reset().read(&mut output);
+            assert_eq!(&output[..], &hex_str_to_bytes(expectations[3])[..]);
+        }
+
+        let bytes = (0..10000).map(|i| (i % 251) as u8).collect::<Vec<u8>>();
+        let expectation = "9d6a1aca8e79d68f7a1ef89a64c1e891ecd92337b266d90edff8f634286c5212";
+
+        for threads in 1..=4 {
+            let mut output = [0u8; 32];
+            let parallel_hash = ParallelHash128::new(64, b"petrel").threads(threads);
+            let parallel_hash = dice_bytes(&bytes)
+                .iter()
+                .fold(parallel_hash, |parallel_hash, seg| parallel_hash.chain(seg));
+            parallel_hash.finalize(&mut output);
+            assert_eq!(&output[..], &hex_str_to_bytes(expectation)[..]);
+        }
+    }
+
+    #[test]
+    fn keccak() {
+        let cases: [(&[u8], [&str; 2]); 4] = [
+            (
+                b"",
+                [
+                    "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470",
+                    "0eab42de4c3ceb9235fc91acffe746b29c29a8c366b7c60e4e67c466f36a4304c00fa9caf9d87976ba469bcbe06713b435f091ef2769fb160cdab33d3670680e",
+                ],
+            ),
+            (
+                b"abc",
+                [
+                    "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45",
+                    "18587dc2ea106b9a1563e32b3312421ca164c7f1f07bc922a9c83d77cea3a1e5d0c69910739025372dc14ac9642629379540c17e2a65b19d77aa511a9d00bb96",
+                ],
+            ),
+            (
+                b"transfer(address,uint256)",
+                [
+                    "a9059cbb2ab09eb219583f4a59a5d0623ade346d962bcd4e46b11da047c9049b",
+                    "c230b8c80dc696ec855a67d69d6424a5fae9f522e11d235199b017375ada73fae19dffbc3e2eac85a91dd2351a4125912ca8a839ca9bd72028d23c280edf34f9",
+                ],
+            ),
+            (
+                &[0xa3u8; 200],
+                [
+                    "3a57666b048777f2c953dc4456f45a2588e1cb6f2da760122d530ac2ce607d4a",
+                    "f4f846d140847539f53c3f082cc4e6810e143a5b4fc62a20597b5d76043246b86bd7149b906140bb9665a6ce83d991f032f2291d2fae80eedfc6f845cc16d5ae",
+                ],
+            ),
+        ];
+
+        for (bytes, expectations) in cases.iter() {
+            check_digest::<[u8; 32], Keccak256Digest>(bytes, &hex_str_to_bytes(expectations[0]));
+            check_digest::<[u8; 64], Keccak512Digest>(bytes, &hex_str_to_bytes(expectations[1]));
+        }
+    }
+
+    #[test]
+    fn eip55() {
+        let checksummed = [
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+            "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+            "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+        ];
+
+        for encoded in checksummed.iter() {
+            let address = eip55::decode(encoded).unwrap();
+            assert_eq!(
+                &address[..],
+                &hex_str_to_bytes(&encoded[2..].to_lowercase())[..]
+            );
+            assert_eq!(&eip55::encode(&address), encoded);
+        }
+
+        let unchecksummed = [
+            "0x52908400098527886E0F7030069857D2E4169EE7",
+            "0x8617E340B3D01FA5F11F306F4090FD50E238070D",
+            "0xde709f2102306220921060314715629080e2fb77",
+            "0x27b1fdb04752bbc536007a920d24acb045561c26",
+        ];
+
+        for encoded in unchecksummed.iter() {
+            let address = eip55::decode(encoded).unwrap();
+            assert_eq!(
+                eip55::encode(&address).to_lowercase(),
+                encoded.to_lowercase()
+            );
+        }
+
+        assert_eq!(
+            eip55::decode("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD"),
+            Err(eip55::Eip55Error::InvalidChecksum)
+        );
+        assert_eq!(
+            eip55::decode("5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"),
+            Err(eip55::Eip55Error::MissingPrefix)
+        );
+        assert_eq!(
+            eip55::decode("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeA"),
+            Err(eip55::Eip55Error::InvalidLength)
+        );
+        assert_eq!(
+            eip55::decode("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeg"),
+            Err(eip55::Eip55Error::InvalidCharacter)
+        );
+
+        let mut public_key = [0u8; 64];
+        public_key.copy_from_slice(&hex_str_to_bytes(
+            "e68acfc0253a10620dff706b0a1b1f1f5833ea3beb3bde2250d5f271f3563606672ebc45e0b7ea2e816ecb70ca03137b1c9476eec63d4632e990020b7b6fba39",
+        ));
+        assert_eq!(
+            eip55::encode(&eip55::address(&public_key)),
+            "0x90F8bf6A479f320ead074411a4B0e7944Ea8c9C1"
+        );
+    }
+
+    #[test]
+    fn md4_md5() {
+        let cases: [(&[u8], [&str; 2]); 7] = [
+            (
+                b"",
+                [
+                    "31d6cfe0d16ae931b73c59d7e0c089c0",
+                    "d41d8cd98f00b204e9800998ecf8427e",
+                ],
+            ),
+            (
+                b"a",
+                [
+                    "bde52cb31de33e46245e05fbdbd6fb24",
+                    "0cc175b9c0f1b6a831c399e269772661",
+                ],
+            ),
+            (
+                b"abc",
+                [
+                    "a448017aaf21d8525fc10ae87aa6729d",
+                    "900150983cd24fb0d6963f7d28e17f72",
+                ],
+            ),
+            (
+                b"message digest",
+                [
+                    "d9130a8164549fe818874806e1c7014b",
+                    "f96b697d7cb7938d525a2f31aaf161d0",
+                ],
+            ),
+            (
+                b"abcdefghijklmnopqrstuvwxyz",
+                [
+                    "d79e1c308aa5bbcdeea8ed63df412da9",
+                    "c3fcd3d76192e4007dfb496cca67e13b",
+                ],
+            ),
+            (
+                b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789",
+                [
+                    "043f8582f241db351ce627e153e7f0e4",
+                    "d174ab98d277d9f5a5611c2c9f419d9f",
+                ],
+            ),
+            (
+                b"12345678901234567890123456789012345678901234567890123456789012345678901234567890",
+                [
+                    "e33b4ddc9c38f2199c3e7b164fcc0536",
+                    "57edf4a22be3c955ac49da2e2107b67a",
+                ],
+            ),
+        ];
+
+        for (bytes, expectations) in cases.iter() {
+            check_digest::<[u8; 16], MD4Digest>(bytes, &hex_str_to_bytes(expectations[0]));
+            check_digest::<[u8; 16], MD5Digest>(bytes, &hex_str_to_bytes(expectations[1]));
+        }
+    }
+
+    #[test]
+    fn ripemd() {
+        let cases: [(&[u8], [&str; 4]); 8] = [
+            (
+                b"",
+                [
+                    "cdf26213a150dc3ecb610f18f6b38b46",
+                    "9c1185a5c5e9fc54612808977ee8f548b2258d31",
+                    "02ba4c4e5f8ecd1877fc52d64d30e37a2d9774fb1e5d026380ae0168e3c5522d",
+                    "22d65d5661536cdc75c1fdf5c6de7b41b9f27325ebc61e8557177d705a0ec880151c3a32a00899b8",
+                ],
+            ),
+            (
+                b"a",
+                [
+                    "86be7afa339d0fc7cfc785e72f578d33",
+                    "0bdc9d2d256b3ee9daae347be6f4dc835a467ffe",
+                    "f9333e45d857f5d90a91bab70a1eba0cfb1be4b0783c9acfcd883a9134692925",
+                    "ce78850638f92658a5a585097579926dda667a5716562cfcf6fbe77f63542f99b04705d6970dff5d",
+                ],
+            ),
+            (
+                b"abc",
+                [
+                    "c14a12199c66e4ba84636b0f69144c77",
+                    "8eb208f7e05d987a9b044a8e98c6b087f15a0bfc",
+                    "afbd6e228b9d8cbbcef5ca2d03e6dba10ac0bc7dcbe4680e1e42d2e975459b65",
+                    "de4c01b3054f8930a79d09ae738e92301e5a17085beffdc1b8d116713e74f82fa942d64cdbc4682d",
+                ],
+            ),
+            (
+                b"message digest",
+                [
+                    "9e327b3d6e523062afc1132d7df9d1b8",
+                    "5d0689ef49d2fae572b881b123a85ffa21595f36",
+                    "87e971759a1ce47a514d5c914c392c9018c7c46bc14465554afcdf54a5070c0e",
+                    "3a8e28502ed45d422f68844f9dd316e7b98533fa3f2a91d29f84d425c88d6b4eff727df66a7c0197",
+                ],
+            ),
+            (
+                b"abcdefghijklmnopqrstuvwxyz",
+                [
+                    "fd2aa607f71dc8f510714922b371834e",
+                    "f71c27109c692c1b56bbdceb5b9d2865b3708dbc",
+                    "649d3034751ea216776bf9a18acc81bc7896118a5197968782dd1fd97d8d5133",
+                    "cabdb1810b92470a2093aa6bce05952c28348cf43ff60841975166bb40ed234004b8824463e6b009",
+                ],
+            ),
+            (
+                b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+                [
+                    "a1aa0689d0fafa2ddc22e88b49133a06",
+                    "12a053384a9c0c88e405a06c27dcf49ada62eb2b",
+                    "3843045583aac6c8c8d9128573e7a9809afb2a0f34ccc36ea9e72f16f6368e3f",
+                    "d034a7950cf722021ba4b84df769a5de2060e259df4c9bb4a4268c0e935bbc7470a969c9d072a1ac",
+                ],
+            ),
+            (
+                b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789",
+                [
+                    "d1e959eb179c911faea4624c60c5c702",
+                    "b0e20b6e3116640286ed3a87a5713079b21f5189",
+                    "5740a408ac16b720b84424ae931cbb1fe363d1d0bf4017f1a89f7ea6de77a0b8",
+                    "ed544940c86d67f250d232c30b7b3e5770e0c60c8cb9a4cafe3b11388af9920e1b99230b843c86a4",
+                ],
+            ),
+            (
+                b"12345678901234567890123456789012345678901234567890123456789012345678901234567890",
+                [
+                    "3f45ef194732c2dbb2c4a2c769795fa3",
+                    "9b752e45573d4b39f4dbd3323cab82bf63326bfb",
+                    "06fdcc7a409548aaf91368c06a6275b553e3f099bf0ea4edfd6778df89a890dd",
+                    "557888af5f6d8ed62ab66945c6d2a0a47ecd5341e915eb8fea1d0524955f825dc717e4a008ab2d42",
+                ],
+            ),
+        ];
+
+        for (bytes, expectations) in cases.iter() {
+            check_digest::<[u8; 16], RIPEMD128Digest>(bytes, &hex_str_to_bytes(expectations[0]));
+            check_digest::<[u8; 20], RIPEMD160Digest>(bytes, &hex_str_to_bytes(expectations[1]));
+            check_digest::<[u8; 32], RIPEMD256Digest>(bytes, &hex_str_to_bytes(expectations[2]));
+            check_digest::<[u8; 40], RIPEMD320Digest>(bytes, &hex_str_to_bytes(expectations[3]));
+        }
+    }
+
+    #[test]
+    fn blake2() {
+        check_digest::<[u8; 64], Blake2b512Digest>(
+            b"abc",
+            &hex_str_to_bytes("ba80a53f981c4d0d6a2797b69f12f6e94c212f14685ac4b74b12bb6fdbffa2d17d87c5392aab792dc252d5de4533cc9518d38aa8dbf1925ab92386edd4009923"),
+        );
+        check_digest::<[u8; 32], Blake2s256Digest>(
+            b"abc",
+            &hex_str_to_bytes("508c5e8c327c14e2e1a72ba34eeb452f37458b209ed63a294d999b4c86675982"),
+        );
+
+        fn check_blake2<const LEN: usize, D: Digest<[u8; LEN]> + Clone>(
+            digest: D,
+            bytes: &[u8],
+            expectation: &str,
+        ) {
+            let expectation = hex_str_to_bytes(expectation);
+            assert_eq!(digest.clone().chain(bytes).finalize().to_vec(), expectation);
+
+            let mut d = digest;
+            for seg in dice_bytes(bytes) {
+                d.update(seg);
+            }
+            assert_eq!(d.finalize_reset().to_vec(), expectation);
+            d.update(bytes);
+            assert_eq!(d.finalize_reset().to_vec(), expectation);
+        }
+
+        let input = (0..=255u8).collect::<Vec<u8>>();
+
+        let kat: [(usize, [&str; 2]); 10] = [
+            (0, [
+                "10ebb67700b1868efb4417987acf4690ae9d972fb7a590c2f02871799aaa4786b5e996e8f0f4eb981fc214b005f42d2ff4233499391653df7aefcbc13fc51568",
+                "48a8997da407876b3d79c0d92325ad3b89cbb754d86ab71aee047ad345fd2c49",
+            ]),
+            (1, [
+                "961f6dd1e4dd30f63901690c512e78e4b45e4742ed197c3c5e45c549fd25f2e4187b0bc9fe30492b16b0d0bc4ef9b0f34c7003fac09a5ef1532e69430234cebd",
+                "40d15fee7c328830166ac3f918650f807e7e01e177258cdc0a39b11f598066f1",
+            ]),
+            (2, [
+                "da2cfbe2d8409a0f38026113884f84b50156371ae304c4430173d08a99d9fb1b983164a3770706d537f49e0c916d9f32b95cc37a95b99d857436f0232c88a965",
+                "6bb71300644cd3991b26ccd4d274acd1adeab8b1d7914546c1198bbe9fc9d803",
+            ]),
+            (63, [
+                "bd965bf31e87d70327536f2a341cebc4768eca275fa05ef98f7f1b71a0351298de006fba73fe6733ed01d75801b4a928e54231b38e38c562b2e33ea1284992fa",
+                "c65382513f07460da39833cb666c5ed82e61b9e998f4b0c4287cee56c3cc9bcd",
+            ]),
+            (64, [
+                "65676d800617972fbd87e4b9514e1c67402b7a331096d3bfac22f1abb95374abc942f16e9ab0ead33b87c91968a6e509e119ff07787b3ef483e1dcdccf6e3022",
+                "8975b0577fd35566d750b362b0897a26c399136df07bababbde6203ff2954ed4",
+            ]),
+            (65, [
+                "939fa189699c5d2c81ddd1ffc1fa207c970b6a3685bb29ce1d3e99d42f2f7442da53e95a72907314f4588399a3ff5b0a92beb3f6be2694f9f86ecf2952d5b41c",
+                "21fe0ceb0052be7fb0f004187cacd7de67fa6eb0938d927677f2398c132317a8",
+            ]),
+            (127, [
+                "76d2d819c92bce55fa8e092ab1bf9b9eab237a25267986cacf2b8ee14d214d730dc9a5aa2d7b596e86a1fd8fa0804c77402d2fcd45083688b218b1cdfa0dcbcb",
+                "ddbfea75cc467882eb3483ce5e2e756a4f4701b76b445519e89f22d60fa86e06",
+            ]),
+            (128, [
+                "72065ee4dd91c2d8509fa1fc28a37c7fc9fa7d5b3f8ad3d0d7a25626b57b1b44788d4caf806290425f9890a3a2a35a905ab4b37acfd0da6e4517b2525c9651e4",
+                "0c311f38c35a4fb90d651c289d486856cd1413df9b0677f53ece2cd9e477c60a",
+            ]),
+            (129, [
+                "64475dfe7600d7171bea0b394e27c9b00d8e74dd1e416a79473682ad3dfdbb706631558055cfc8a40e07bd015a4540dcdea15883cbbf31412df1de1cd4152b91",
+                "46a73a8dd3e70f59d3942c01df599def783c9da82fd83222cd662b53dce7dbdf",
+            ]),
+            (255, [
+                "142709d62e28fcccd0af97fad0f8465b971e82201dc51070faa0372aa43e92484be1c1e73ba10906d5d1853db6a4106e0a7bf9800d373d6dee2d46d62ef2a461",
+                "3fb735061abc519dfe979e54c1ee5bfad0a9d858b3315bad34bde999efd724dd",
+            ]),
+        ];
+
+        for (len, expectations) in kat.iter() {
+            check_blake2(
+                Blake2b::<64>::new_keyed(&input[0..64]),
+                &input[0..*len],
+                expectations[0],
+            );
+            check_blake2(
+                Blake2s::<32>::new_keyed(&input[0..32]),
+                &input[0..*len],
+                expectations[1],
+            );
+        }
+
+        let fox = b"The quick brown fox jumps over the lazy dog";
+
+        check_blake2(
+            Blake2b::<32>::with_params(
+                &Blake2bParams::new()
+                    .key(b"petrel key")
+                    .salt(b"salty salt")
+                    .personal(b"personalization!"),
+            ),
+            fox,
+            "9cda08467e66c8c7c7bdb87a844819ce00ad8bf048283f2083cea4d9121702a5",
+        );
+        check_blake2(
+            Blake2s::<20>::with_params(
+                &Blake2sParams::new()
+                    .key(b"petrel key")
+                    .salt(b"saltsalt")
+                    .personal(b"personal"),
+            ),
+            fox,
+            "dbbda6e73a16dd368f7e6da192d74bc9d798c92b",
+        );
+
+        let tree_b = Blake2bParams::new()
+            .fanout(2)
+            .depth(2)
+            .leaf_length(4096)
+            .inner_length(64)
+            .last_node(true);
+        let tree_s = Blake2sParams::new()
+            .fanout(2)
+            .depth(2)
+            .leaf_length(4096)
+            .inner_length(32)
+            .last_node(true);
+
+        check_blake2(
+            Blake2b::<64>::with_params(&tree_b.clone().node_offset(1)),
+            &input,
+            "10c0ac46e365ce04733a7edfe578eb1f801a5bf220ef7ba3ad981f7bf1dab57292a34bbccef2598f5d920751a8aadaae5dedce46df2bdfb14c2f0fa2eaf2a1aa",
+        );
+        check_blake2(
+            Blake2b::<64>::with_params(&tree_b.node_depth(1)),
+            &input[0..128],
+            "6841dc3b79e240fa8809958795ec3e9bfc47c9cb5bdc7991724d7e27e29c6cf6e598615d1485c0f61f2d124a7dc32dca45e54c2205e392488ac73f9786f4b3fe",
+        );
+        check_blake2(
+            Blake2s::<32>::with_params(&tree_s.clone().node_offset(1)),
+            &input,
+            "65cc26f221632458760b4335847e89feaf86484a219fc5ce2f609b4adf245ab7",
+        );
+        check_blake2(
+            Blake2s::<32>::with_params(&tree_s.node_depth(1)),
+            &input[0..64],
+            "b1242982fe9966c1bf546cdbddff1c7267fb82796aaaf5480357f10d589b0e4a",
+        );
+
+        let keyed = Blake2bParams::new().key(b"key");
+        let mut output = [0u8; 20];
+        Blake2b::<64>::with_digest_length(&keyed, 20)
+            .chain(&input)
+            .finalize_variable(&mut output);
+        assert_eq!(
+            output,
+            Blake2b::<20>::new_keyed(b"key").chain(&input).finalize()
+        );
+
+        let mut d = Blake2b::<64>::with_digest_length(&Blake2bParams::new(), 32);
+        let mut output = [0u8; 32];
+        for seg in dice_bytes(&input) {
+            d.update(seg);
+        }
+        d.finalize_variable_reset(&mut output);
+        assert_eq!(output, Blake2b::<32>::hash(&input));
+        d.update(&input);
+        d.finalize_variable(&mut output);
+        assert_eq!(output, Blake2b::<32>::hash(&input));
+
+        let mut output = [0u8; 16];
+        Blake2s::<32>::with_digest_length(&Blake2sParams::new(), 16)
+            .chain(&input)
+            .finalize_variable(&mut output);
+        assert_eq!(output, Blake2s::<16>::hash(&input));
+    }
+
+    #[test]
+    fn blake3() {
+        let key = b"whats the Elvish word for friend";
+        let context = "BLAKE3 2019-12-27 16:29:52 test vectors context";
+
+        let cases: [(usize, [&str; 3]); 22] = [
+            (0, [
+                "af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262e00f03e7b69af26b7faaf09fcd333050338ddfe085b8cc869ca98b206c08243a26f5487789e8f660afe6c99ef9e0c52b92e7393024a80459cf91f476f9ffdbda7001c22e159b402631f277ca96f2defdf1078282314e763699a31c5363165421cce14d",
+                "92b2b75604ed3c761f9d6f62392c8a9227ad0ea3f09573e783f1498a4ed60d26b18171a2f22a4b94822c701f107153dba24918c4bae4d2945c20ece13387627d3b73cbf97b797d5e59948c7ef788f54372df45e45e4293c7dc18c1d41144a9758be58960856be1eabbe22c2653190de560ca3b2ac4aa692a9210694254c371e851bc8f",
+                "2cc39783c223154fea8dfb7c1b1660f2ac2dcbd1c1de8277b0b0dd39b7e50d7d905630c8be290dfcf3e6842f13bddd573c098c3f17361f1f206b8cad9d088aa4a3f746752c6b0ce6a83b0da81d59649257cdf8eb3e9f7d4998e41021fac119deefb896224ac99f860011f73609e6e0e4540f93b273e56547dfd3aa1a035ba6689d89a0",
+            ]),
+            (1, [
+                "2d3adedff11b61f14c886e35afa036736dcd87a74d27b5c1510225d0f592e213c3a6cb8bf623e20cdb535f8d1a5ffb86342d9c0b64aca3bce1d31f60adfa137b358ad4d79f97b47c3d5e79f179df87a3b9776ef8325f8329886ba42f07fb138bb502f4081cbcec3195c5871e6c23e2cc97d3c69a613eba131e5f1351f3f1da786545e5",
+                "6d7878dfff2f485635d39013278ae14f1454b8c0a3a2d34bc1ab38228a80c95b6568c0490609413006fbd428eb3fd14e7756d90f73a4725fad147f7bf70fd61c4e0cf7074885e92b0e3f125978b4154986d4fb202a3f331a3fb6cf349a3a70e49990f98fe4289761c8602c4e6ab1138d31d3b62218078b2f3ba9a88e1d08d0dd4cea11",
+                "b3e2e340a117a499c6cf2398a19ee0d29cca2bb7404c73063382693bf66cb06c5827b91bf889b6b97c5477f535361caefca0b5d8c4746441c57617111933158950670f9aa8a05d791daae10ac683cbef8faf897c84e6114a59d2173c3f417023a35d6983f2c7dfa57e7fc559ad751dbfb9ffab39c2ef8c4aafebc9ae973a64f0c76551",
+            ]),
+            (1023, [
+                "10108970eeda3eb932baac1428c7a2163b0e924c9a9e25b35bba72b28f70bd11a182d27a591b05592b15607500e1e8dd56bc6c7fc063715b7a1d737df5bad3339c56778957d870eb9717b57ea3d9fb68d1b55127bba6a906a4a24bbd5acb2d123a37b28f9e9a81bbaae360d58f85e5fc9d75f7c370a0cc09b6522d9c8d822f2f28f485",
+                "c951ecdf03288d0fcc96ee3413563d8a6d3589547f2c2fb36d9786470f1b9d6e890316d2e6d8b8c25b0a5b2180f94fb1a158ef508c3cde45e2966bd796a696d3e13efd86259d756387d9becf5c8bf1ce2192b87025152907b6d8cc33d17826d8b7b9bc97e38c3c85108ef09f013e01c229c20a83d9e8efac5b37470da28575fd755a10",
+                "74a16c1c3d44368a86e1ca6df64be6a2f64cce8f09220787450722d85725dea59c413264404661e9e4d955409dfe4ad3aa487871bcd454ed12abfe2c2b1eb7757588cf6cb18d2eccad49e018c0d0fec323bec82bf1644c6325717d13ea712e6840d3e6e730d35553f59eff5377a9c350bcc1556694b924b858f329c44ee64b884ef00d",
+            ]),
+            (1024, [
+                "42214739f095a406f3fc83deb889744ac00df831c10daa55189b5d121c855af71cf8107265ecdaf8505b95d8fcec83a98a6a96ea5109d2c179c47a387ffbb404756f6eeae7883b446b70ebb144527c2075ab8ab204c0086bb22b7c93d465efc57f8d917f0b385c6df265e77003b85102967486ed57db5c5ca170ba441427ed9afa684e",
+                "75c46f6f3d9eb4f55ecaaee480db732e6c2105546f1e675003687c31719c7ba4a78bc838c72852d4f49c864acb7adafe2478e824afe51c8919d06168414c265f298a8094b1ad813a9b8614acabac321f24ce61c5a5346eb519520d38ecc43e89b5000236df0597243e4d2493fd626730e2ba17ac4d8824d09d1a4a8f57b8227778e2de",
+                "7356cd7720d5b66b6d0697eb3177d9f8d73a4a5c5e968896eb6a6896843027066c23b601d3ddfb391e90d5c8eccdef4ae2a264bce9e612ba15e2bc9d654af1481b2e75dbabe615974f1070bba84d56853265a34330b4766f8e75edd1f4a1650476c10802f22b64bd3919d246ba20a17558bc51c199efdec67e80a227251808d8ce5bad",
+            ]),
+            (1025, [
+                "d00278ae47eb27b34faecf67b4fe263f82d5412916c1ffd97c8cb7fb814b8444f4c4a22b4b399155358a994e52bf255de60035742ec71bd08ac275a1b51cc6bfe332b0ef84b409108cda080e6269ed4b3e2c3f7d722aa4cdc98d16deb554e5627be8f955c98e1d5f9565a9194cad0c4285f93700062d9595adb992ae68ff12800ab67a",
+                "357dc55de0c7e382c900fd6e320acc04146be01db6a8ce7210b7189bd664ea69362396b77fdc0d2634a552970843722066c3c15902ae5097e00ff53f1e116f1cd5352720113a837ab2452cafbde4d54085d9cf5d21ca613071551b25d52e69d6c81123872b6f19cd3bc1333edf0c52b94de23ba772cf82636cff4542540a7738d5b930",
+                "effaa245f065fbf82ac186839a249707c3bddf6d3fdda22d1b95a3c970379bcb5d31013a167509e9066273ab6e2123bc835b408b067d88f96addb550d96b6852dad38e320b9d940f86db74d398c770f462118b35d2724efa13da97194491d96dd37c3c09cbef665953f2ee85ec83d88b88d11547a6f911c8217cca46defa2751e7f3ad",
+            ]),
+            (2048, [
+                "e776b6028c7cd22a4d0ba182a8bf62205d2ef576467e838ed6f2529b85fba24a9a60bf80001410ec9eea6698cd537939fad4749edd484cb541aced55cd9bf54764d063f23f6f1e32e12958ba5cfeb1bf618ad094266d4fc3c968c2088f677454c288c67ba0dba337b9d91c7e1ba586dc9a5bc2d5e90c14f53a8863ac75655461cea8f9",
+                "879cf1fa2ea0e79126cb1063617a05b6ad9d0b696d0d757cf053439f60a99dd10173b961cd574288194b23ece278c330fbb8585485e74967f31352a8183aa782b2b22f26cdcadb61eed1a5bc144b8198fbb0c13abbf8e3192c145d0a5c21633b0ef86054f42809df823389ee40811a5910dcbd1018af31c3b43aa55201ed4edaac74fe",
+                "7b2945cb4fef70885cc5d78a87bf6f6207dd901ff239201351ffac04e1088a23e2c11a1ebffcea4d80447867b61badb1383d842d4e79645d48dd82ccba290769caa7af8eaa1bd78a2a5e6e94fbdab78d9c7b74e894879f6a515257ccf6f95056f4e25390f24f6b35ffbb74b766202569b1d797f2d4bd9d17524c720107f985f4ddc583",
+            ]),
+            (2049, [
+                "5f4d72f40d7a5f82b15ca2b2e44b1de3c2ef86c426c95c1af0b687952256303096de31d71d74103403822a2e0bc1eb193e7aecc9643a76b7bbc0c9f9c52e8783aae98764ca468962b5c2ec92f0c74eb5448d519713e09413719431c802f948dd5d90425a4ecdadece9eb178d80f26efccae630734dff63340285adec2aed3b51073ad3",
+                "9f29700902f7c86e514ddc4df1e3049f258b2472b6dd5267f61bf13983b78dd5f9a88abfefdfa1e00b418971f2b39c64ca621e8eb37fceac57fd0c8fc8e117d43b81447be22d5d8186f8f5919ba6bcc6846bd7d50726c06d245672c2ad4f61702c646499ee1173daa061ffe15bf45a631e2946d616a4c345822f1151284712f76b2b0e",
+                "2ea477c5515cc3dd606512ee72bb3e0e758cfae7232826f35fb98ca1bcbdf27316d8e9e79081a80b046b60f6a263616f33ca464bd78d79fa18200d06c7fc9bffd808cc4755277a7d5e09da0f29ed150f6537ea9bed946227ff184cc66a72a5f8c1e4bd8b04e81cf40fe6dc4427ad5678311a61f4ffc39d195589bdbc670f63ae70f4b6",
+            ]),
+            (3072, [
+                "b98cb0ff3623be03326b373de6b9095218513e64f1ee2edd2525c7ad1e5cffd29a3f6b0b978d6608335c09dc94ccf682f9951cdfc501bfe47b9c9189a6fc7b404d120258506341a6d802857322fbd20d3e5dae05b95c88793fa83db1cb08e7d8008d1599b6209d78336e24839724c191b2a52a80448306e0daa84a3fdb566661a37e11",
+                "044a0e7b172a312dc02a4c9a818c036ffa2776368d7f528268d2e6b5df19177022f302d0529e4174cc507c463671217975e81dab02b8fdeb0d7ccc7568dd22574c783a76be215441b32e91b9a904be8ea81f7a0afd14bad8ee7c8efc305ace5d3dd61b996febe8da4f56ca0919359a7533216e2999fc87ff7d8f176fbecb3d6f34278b",
+                "050df97f8c2ead654d9bb3ab8c9178edcd902a32f8495949feadcc1e0480c46b3604131bbd6e3ba573b6dd682fa0a63e5b165d39fc43a625d00207607a2bfeb65ff1d29292152e26b298868e3b87be95d6458f6f2ce6118437b632415abe6ad522874bcd79e4030a5e7bad2efa90a7a7c67e93f0a18fb28369d0a9329ab5c24134ccb0",
+            ]),
+            (3073, [
+                "7124b49501012f81cc7f11ca069ec9226cecb8a2c850cfe644e327d22d3e1cd39a27ae3b79d68d89da9bf25bc27139ae65a324918a5f9b7828181e52cf373c84f35b639b7fccbb985b6f2fa56aea0c18f531203497b8bbd3a07ceb5926f1cab74d14bd66486d9a91eba99059a98bd1cd25876b2af5a76c3e9eed554ed72ea952b603bf",
+                "68dede9bef00ba89e43f31a6825f4cf433389fedae75c04ee9f0cf16a427c95a96d6da3fe985054d3478865be9a092250839a697bbda74e279e8a9e69f0025e4cfddd6cfb434b1cd9543aaf97c635d1b451a4386041e4bb100f5e45407cbbc24fa53ea2de3536ccb329e4eb9466ec37093a42cf62b82903c696a93a50b702c80f3c3c5",
+                "72613c9ec9ff7e40f8f5c173784c532ad852e827dba2bf85b2ab4b76f7079081576288e552647a9d86481c2cae75c2dd4e7c5195fb9ada1ef50e9c5098c249d743929191441301c69e1f48505a4305ec1778450ee48b8e69dc23a25960fe33070ea549119599760a8a2d28aeca06b8c5e9ba58bc19e11fe57b6ee98aa44b2a8e6b14a5",
+            ]),
+            (4096, [
+                "015094013f57a5277b59d8475c0501042c0b642e531b0a1c8f58d2163229e9690289e9409ddb1b99768eafe1623da896faf7e1114bebeadc1be30829b6f8af707d85c298f4f0ff4d9438aef948335612ae921e76d411c3a9111df62d27eaf871959ae0062b5492a0feb98ef3ed4af277f5395172dbe5c311918ea0074ce0036454f620",
+                "befc660aea2f1718884cd8deb9902811d332f4fc4a38cf7c7300d597a081bfc0bbb64a36edb564e01e4b4aaf3b060092a6b838bea44afebd2deb8298fa562b7b597c757b9df4c911c3ca462e2ac89e9a787357aaf74c3b56d5c07bc93ce899568a3eb17d9250c20f6c5f6c1e792ec9a2dcb715398d5a6ec6d5c54f586a00403a1af1de",
+                "1e0d7f3db8c414c97c6307cbda6cd27ac3b030949da8e23be1a1a924ad2f25b9d78038f7b198596c6cc4a9ccf93223c08722d684f240ff6569075ed81591fd93f9fff1110b3a75bc67e426012e5588959cc5a4c192173a03c00731cf84544f65a2fb9378989f72e9694a6a394a8a30997c2e67f95a504e631cd2c5f55246024761b245",
+            ]),
+            (4097, [
+                "9b4052b38f1c5fc8b1f9ff7ac7b27cd242487b3d890d15c96a1c25b8aa0fb99505f91b0b5600a11251652eacfa9497b31cd3c409ce2e45cfe6c0a016967316c426bd26f619eab5d70af9a418b845c608840390f361630bd497b1ab44019316357c61dbe091ce72fc16dc340ac3d6e009e050b3adac4b5b2c92e722cffdc46501531956",
+                "00df940cd36bb9fa7cbbc3556744e0dbc8191401afe70520ba292ee3ca80abbc606db4976cfdd266ae0abf667d9481831ff12e0caa268e7d3e57260c0824115a54ce595ccc897786d9dcbf495599cfd90157186a46ec800a6763f1c59e36197e9939e900809f7077c102f888caaf864b253bc41eea812656d46742e4ea42769f89b83f",
+                "aca51029626b55fda7117b42a7c211f8c6e9ba4fe5b7a8ca922f34299500ead8a897f66a400fed9198fd61dd2d58d382458e64e100128075fc54b860934e8de2e84170734b06e1d212a117100820dbc48292d148afa50567b8b84b1ec336ae10d40c8c975a624996e12de31abbe135d9d159375739c333798a80c64ae895e51e22f3ad",
+            ]),
+            (5120, [
+                "9cadc15fed8b5d854562b26a9536d9707cadeda9b143978f319ab34230535833acc61c8fdc114a2010ce8038c853e121e1544985133fccdd0a2d507e8e615e611e9a0ba4f47915f49e53d721816a9198e8b30f12d20ec3689989175f1bf7a300eee0d9321fad8da232ece6efb8e9fd81b42ad161f6b9550a069e66b11b40487a5f5059",
+                "2c493e48e9b9bf31e0553a22b23503c0a3388f035cece68eb438d22fa1943e209b4dc9209cd80ce7c1f7c9a744658e7e288465717ae6e56d5463d4f80cdb2ef56495f6a4f5487f69749af0c34c2cdfa857f3056bf8d807336a14d7b89bf62bef2fb54f9af6a546f818dc1e98b9e07f8a5834da50fa28fb5874af91bf06020d1bf0120e",
+                "7a7acac8a02adcf3038d74cdd1d34527de8a0fcc0ee3399d1262397ce5817f6055d0cefd84d9d57fe792d65a278fd20384ac6c30fdb340092f1a74a92ace99c482b28f0fc0ef3b923e56ade20c6dba47e49227166251337d80a037e987ad3a7f728b5ab6dfafd6e2ab1bd583a95d9c895ba9c2422c24ea0f62961f0dca45cad47bfa0d",
+            ]),
+            (5121, [
+                "628bd2cb2004694adaab7bbd778a25df25c47b9d4155a55f8fbd79f2fe154cff96adaab0613a6146cdaabe498c3a94e529d3fc1da2bd08edf54ed64d40dcd6777647eac51d8277d70219a9694334a68bc8f0f23e20b0ff70ada6f844542dfa32cd4204ca1846ef76d811cdb296f65e260227f477aa7aa008bac878f72257484f2b6c95",
+                "6ccf1c34753e7a044db80798ecd0782a8f76f33563accaddbfbb2e0ea4b2d0240d07e63f13667a8d1490e5e04f13eb617aea16a8c8a5aaed1ef6fbde1b0515e3c81050b361af6ead126032998290b563e3caddeaebfab592e155f2e161fb7cba939092133f23f9e65245e58ec23457b78a2e8a125588aad6e07d7f11a85b88d375b72d",
+                "b07f01e518e702f7ccb44a267e9e112d403a7b3f4883a47ffbed4b48339b3c341a0add0ac032ab5aaea1e4e5b004707ec5681ae0fcbe3796974c0b1cf31a194740c14519273eedaabec832e8a784b6e7cfc2c5952677e6c3f2c3914454082d7eb1ce1766ac7d75a4d3001fc89544dd46b5147382240d689bbbaefc359fb6ae30263165",
+            ]),
+            (6144, [
+                "3e2e5b74e048f3add6d21faab3f83aa44d3b2278afb83b80b3c35164ebeca2054d742022da6fdda444ebc384b04a54c3ac5839b49da7d39f6d8a9db03deab32aade156c1c0311e9b3435cde0ddba0dce7b26a376cad121294b689193508dd63151603c6ddb866ad16c2ee41585d1633a2cea093bea714f4c5d6b903522045b20395c83",
+                "3d6b6d21281d0ade5b2b016ae4034c5dec10ca7e475f90f76eac7138e9bc8f1dc35754060091dc5caf3efabe0603c60f45e415bb3407db67e6beb3d11cf8e4f7907561f05dace0c15807f4b5f389c841eb114d81a82c02a00b57206b1d11fa6e803486b048a5ce87105a686dee041207e095323dfe172df73deb8c9532066d88f9da7e",
+                "2a95beae63ddce523762355cf4b9c1d8f131465780a391286a5d01abb5683a1597099e3c6488aab6c48f3c15dbe1942d21dbcdc12115d19a8b8465fb54e9053323a9178e4275647f1a9927f6439e52b7031a0b465c861a3fc531527f7758b2b888cf2f20582e9e2c593709c0a44f9c6e0f8b963994882ea4168827823eef1f64169fef",
+            ]),
+            (6145, [
+                "f1323a8631446cc50536a9f705ee5cb619424d46887f3c376c695b70e0f0507f18a2cfdd73c6e39dd75ce7c1c6e3ef238fd54465f053b25d21044ccb2093beb015015532b108313b5829c3621ce324b8e14229091b7c93f32db2e4e63126a377d2a63a3597997d4f1cba59309cb4af240ba70cebff9a23d5e3ff0cdae2cfd54e070022",
+                "9ac301e9e39e45e3250a7e3b3df701aa0fb6889fbd80eeecf28dbc6300fbc539f3c184ca2f59780e27a576c1d1fb9772e99fd17881d02ac7dfd39675aca918453283ed8c3169085ef4a466b91c1649cc341dfdee60e32231fc34c9c4e0b9a2ba87ca8f372589c744c15fd6f985eec15e98136f25beeb4b13c4e43dc84abcc79cd4646c",
+                "379bcc61d0051dd489f686c13de00d5b14c505245103dc040d9e4dd1facab8e5114493d029bdbd295aaa744a59e31f35c7f52dba9c3642f773dd0b4262a9980a2aef811697e1305d37ba9d8b6d850ef07fe41108993180cf779aeece363704c76483458603bbeeb693cffbbe5588d1f3535dcad888893e53d977424bb707201569a8d2",
+            ]),
+            (7168, [
+                "61da957ec2499a95d6b8023e2b0e604ec7f6b50e80a9678b89d2628e99ada77a5707c321c83361793b9af62a40f43b523df1c8633cecb4cd14d00bdc79c78fca5165b863893f6d38b02ff7236c5a9a8ad2dba87d24c547cab046c29fc5bc1ed142e1de4763613bb162a5a538e6ef05ed05199d751f9eb58d332791b8d73fb74e4fce95",
+                "b42835e40e9d4a7f42ad8cc04f85a963a76e18198377ed84adddeaecacc6f3fca2f01d5277d69bb681c70fa8d36094f73ec06e452c80d2ff2257ed82e7ba348400989a65ee8daa7094ae0933e3d2210ac6395c4af24f91c2b590ef87d7788d7066ea3eaebca4c08a4f14b9a27644f99084c3543711b64a070b94f2c9d1d8a90d035d52",
+                "11c37a112765370c94a51415d0d651190c288566e295d505defdad895dae223730d5a5175a38841693020669c7638f40b9bc1f9f39cf98bda7a5b54ae24218a800a2116b34665aa95d846d97ea988bfcb53dd9c055d588fa21ba78996776ea6c40bc428b53c62b5f3ccf200f647a5aae8067f0ea1976391fcc72af1945100e2a6dcb88",
+            ]),
+            (7169, [
+                "a003fc7a51754a9b3c7fae0367ab3d782dccf28855a03d435f8cfe74605e781798a8b20534be1ca9eb2ae2df3fae2ea60e48c6fb0b850b1385b5de0fe460dbe9d9f9b0d8db4435da75c601156df9d047f4ede008732eb17adc05d96180f8a73548522840779e6062d643b79478a6e8dbce68927f36ebf676ffa7d72d5f68f050b119c8",
+                "ed9b1a922c046fdb3d423ae34e143b05ca1bf28b710432857bf738bcedbfa5113c9e28d72fcbfc020814ce3f5d4fc867f01c8f5b6caf305b3ea8a8ba2da3ab69fabcb438f19ff11f5378ad4484d75c478de425fb8e6ee809b54eec9bdb184315dc856617c09f5340451bf42fd3270a7b0b6566169f242e533777604c118a6358250f54",
+                "554b0a5efea9ef183f2f9b931b7497995d9eb26f5c5c6dad2b97d62fc5ac31d99b20652c016d88ba2a611bbd761668d5eda3e568e940faae24b0d9991c3bd25a65f770b89fdcadabcb3d1a9c1cb63e69721cacf1ae69fefdcef1e3ef41bc5312ccc17222199e47a26552c6adc460cf47a72319cb5039369d0060eaea59d6c65130f1dd",
+            ]),
+            (8192, [
+                "aae792484c8efe4f19e2ca7d371d8c467ffb10748d8a5a1ae579948f718a2a635fe51a27db045a567c1ad51be5aa34c01c6651c4d9b5b5ac5d0fd58cf18dd61a47778566b797a8c67df7b1d60b97b19288d2d877bb2df417ace009dcb0241ca1257d62712b6a4043b4ff33f690d849da91ea3bf711ed583cb7b7a7da2839ba71309bbf",
+                "dc9637c8845a770b4cbf76b8daec0eebf7dc2eac11498517f08d44c8fc00d58a4834464159dcbc12a0ba0c6d6eb41bac0ed6585cabfe0aca36a375e6c5480c22afdc40785c170f5a6b8a1107dbee282318d00d915ac9ed1143ad40765ec120042ee121cd2baa36250c618adaf9e27260fda2f94dea8fb6f08c04f8f10c78292aa46102",
+                "ad01d7ae4ad059b0d33baa3c01319dcf8088094d0359e5fd45d6aeaa8b2d0c3d4c9e58958553513b67f84f8eac653aeeb02ae1d5672dcecf91cd9985a0e67f4501910ecba25555395427ccc7241d70dc21c190e2aadee875e5aae6bf1912837e53411dabf7a56cbf8e4fb780432b0d7fe6cec45024a0788cf5874616407757e9e6bef7",
+            ]),
+            (8193, [
+                "bab6c09cb8ce8cf459261398d2e7aef35700bf488116ceb94a36d0f5f1b7bc3bb2282aa69be089359ea1154b9a9286c4a56af4de975a9aa4a5c497654914d279bea60bb6d2cf7225a2fa0ff5ef56bbe4b149f3ed15860f78b4e2ad04e158e375c1e0c0b551cd7dfc82f1b155c11b6b3ed51ec9edb30d133653bb5709d1dbd55f4e1ff6",
+                "954a2a75420c8d6547e3ba5b98d963e6fa6491addc8c023189cc519821b4a1f5f03228648fd983aef045c2fa8290934b0866b615f585149587dda2299039965328835a2b18f1d63b7e300fc76ff260b571839fe44876a4eae66cbac8c67694411ed7e09df51068a22c6e67d6d3dd2cca8ff12e3275384006c80f4db68023f24eebba57",
+                "af1e0346e389b17c23200270a64aa4e1ead98c61695d917de7d5b00491c9b0f12f20a01d6d622edf3de026a4db4e4526225debb93c1237934d71c7340bb5916158cbdafe9ac3225476b6ab57a12357db3abbad7a26c6e66290e44034fb08a20a8d0ec264f309994d2810c49cfba6989d7abb095897459f5425adb48aba07c5fb3c83c0",
+            ]),
+            (16384, [
+                "f875d6646de28985646f34ee13be9a576fd515f76b5b0a26bb324735041ddde49d764c270176e53e97bdffa58d549073f2c660be0e81293767ed4e4929f9ad34bbb39a529334c57c4a381ffd2a6d4bfdbf1482651b172aa883cc13408fa67758a3e47503f93f87720a3177325f7823251b85275f64636a8f1d599c2e49722f42e93893",
+                "9e9fc4eb7cf081ea7c47d1807790ed211bfec56aa25bb7037784c13c4b707b0df9e601b101e4cf63a404dfe50f2e1865bb12edc8fca166579ce0c70dba5a5c0fc960ad6f3772183416a00bd29d4c6e651ea7620bb100c9449858bf14e1ddc9ecd35725581ca5b9160de04060045993d972571c3e8f71e9d0496bfa744656861b169d65",
+                "160e18b5878cd0df1c3af85eb25a0db5344d43a6fbd7a8ef4ed98d0714c3f7e160dc0b1f09caa35f2f417b9ef309dfe5ebd67f4c9507995a531374d099cf8ae317542e885ec6f589378864d3ea98716b3bbb65ef4ab5e0ab5bb298a501f19a41ec19af84a5e6b428ecd813b1a47ed91c9657c3fba11c406bc316768b58f6802c9e9b57",
+            ]),
+            (31744, [
+                "62b6960e1a44bcc1eb1a611a8d6235b6b4b78f32e7abc4fb4c6cdcce94895c47860cc51f2b0c28a7b77304bd55fe73af663c02d3f52ea053ba43431ca5bab7bfea2f5e9d7121770d88f70ae9649ea713087d1914f7f312147e247f87eb2d4ffef0ac978bf7b6579d57d533355aa20b8b77b13fd09748728a5cc327a8ec470f4013226f",
+                "efa53b389ab67c593dba624d898d0f7353ab99e4ac9d42302ee64cbf9939a4193a7258db2d9cd32a7a3ecfce46144114b15c2fcb68a618a976bd74515d47be08b628be420b5e830fade7c080e351a076fbc38641ad80c736c8a18fe3c66ce12f95c61c2462a9770d60d0f77115bbcd3782b593016a4e728d4c06cee4505cb0c08a42ec",
+                "39772aef80e0ebe60596361e45b061e8f417429d529171b6764468c22928e28e9759adeb797a3fbf771b1bcea30150a020e317982bf0d6e7d14dd9f064bc11025c25f31e81bd78a921db0174f03dd481d30e93fd8e90f8b2fee209f849f2d2a52f31719a490fb0ba7aea1e09814ee912eba111a9fde9d5c274185f7bae8ba85d300a2b",
+            ]),
+            (102400, [
+                "bc3e3d41a1146b069abffad3c0d44860cf664390afce4d9661f7902e7943e085e01c59dab908c04c3342b816941a26d69c2605ebee5ec5291cc55e15b76146e6745f0601156c3596cb75065a9c57f35585a52e1ac70f69131c23d611ce11ee4ab1ec2c009012d236648e77be9295dd0426f29b764d65de58eb7d01dd42248204f45f8e",
+                "1c35d1a5811083fd7119f5d5d1ba027b4d01c0c6c49fb6ff2cf75393ea5db4a7f9dbdd3e1d81dcbca3ba241bb18760f207710b751846faaeb9dff8262710999a59b2aa1aca298a032d94eacfadf1aa192418eb54808db23b56e34213266aa08499a16b354f018fc4967d05f8b9d2ad87a7278337be9693fc638a3bfdbe314574ee6fc4",
+                "4652cff7a3f385a6103b5c260fc1593e13c778dbe608efb092fe7ee69df6e9c6d83a3e041bc3a48df2879f4a0a3ed40e7c961c73eff740f3117a0504c2dff4786d44fb17f1549eb0ba585e40ec29bf7732f0b7e286ff8acddc4cb1e23b87ff5d824a986458dcc6a04ac83969b80637562953df51ed1a7e90a7926924d2763778be8560",
+            ]),
+        ];
+
+        fn check_reader(mut digest: Blake3Digest, bytes: &[u8], expectation: &[u8]) {
+            digest.update(bytes);
+            let mut reader = digest.finalize_xof();
+            let mut output = vec![0u8; expectation.len()];
+            let mut offset = 0;
+            for seg in dice_bytes(expectation) {
+                reader.read(&mut output[offset..offset + seg.len()]);
+                offset += seg.len();
+            }
+            assert_eq!(output, expectation);
+        }
+
+        for (len, expectations) in cases.iter() {
+            let bytes = (0..*len).map(|i| (i % 251) as u8).collect::<Vec<u8>>();
+            let hash = hex_str_to_bytes(expectations[0]);
+            let keyed_hash = hex_str_to_bytes(expectations[1]);
+            let derive_key = hex_str_to_bytes(expectations[2]);
+
+            check_digest::<[u8; 32], Blake3Digest>(&bytes, &hash[0..32]);
+            assert_eq!(Blake3Digest::keyed_hash(key, &bytes), keyed_hash[0..32]);
+            assert_eq!(Blake3Digest::derive_key(context, &bytes), derive_key[0..32]);
+
+            check_reader(Blake3Digest::new(), &bytes, &hash);
+            check_reader(Blake3Digest::new_keyed(key), &bytes, &keyed_hash);
+            check_reader(Blake3Digest::new_derive_key(context), &bytes, &derive_key);
+
+            for threads in [2, 3, 8] {
+                let mut d = Blake3Digest::new().threads(threads);
+                d.update(&bytes);
+                assert_eq!(d.finalize_reset(), hash[0..32]);
+                d.update(&bytes);
+                assert_eq!(d.finalize(), hash[0..32]);
+            }
+        }
+    }
+
+    #[test]
+    fn sm3() {
+        let cases: [(&[u8], &str); 4] = [
+            (
+                b"",
+                "1ab21d8355cfa17f8e61194831e81a8f22bec8c728fefb747ed035eb5082aa2b",
+            ),
+            (
+                b"abc",
+                "66c7f0f462eeedd9d1f2d46bdc10e4e24167c4875cf2f7a2297da02b8f4ba8e0",
+            ),
+            (
+                b"abcdabcdabcdabcdabcdabcdabcdabcdabcdabcdabcdabcdabcdabcdabcdabcd",
+                "debe9ff92275b8a138604889c18e5a4d6fdb70e5387e5765293dcba39c0c5732",
+            ),
+            (
+                b"The quick brown fox jumps over the lazy dog",
+                "5fdfe814b8573ca021983970fc79b2218c9570369b4859684e2e4c3fc76cb8ea",
+            ),
+        ];
+
+        for (bytes, expectation) in cases.iter() {
+            check_digest::<[u8; 32], SM3Digest>(bytes, &hex_str_to_bytes(expectation));
+        }
+    }
+
+    #[test]
+    fn streebog() {
+        let m1 = b"012345678901234567890123456789012345678901234567890123456789012";
+        let m2 = hex_str_to_bytes("d1e520e2e5f2f0e82c20d1f2f0e8e1eee6e820e2edf3f6e82c20e2e5fef2fa20f120eceef0ff20f1f2f0e5ebe0ece820ede020f5f0e0e1f0fbff20efebfaeafb20c8e3eef0e5e2fb");
+        let bytes = (0..=255u8).collect::<Vec<u8>>();
+
+        let cases: [(&[u8], [&str; 2]); 6] = [
+            (b"", [
+                "3f539a213e97c802cc229d474c6aa32a825a360b2a933a949fd925208d9ce1bb",
+                "8e945da209aa869f0455928529bcae4679e9873ab707b55315f56ceb98bef0a7362f715528356ee83cda5f2aac4c6ad2ba3a715c1bcd81cb8e9f90bf4c1c1a8a",
+            ]),
+            (b"abc", [
+                "4e2919cf137ed41ec4fb6270c61826cc4fffb660341e0af3688cd0626d23b481",
+                "28156e28317da7c98f4fe2bed6b542d0dab85bb224445fcedaf75d46e26d7eb8d5997f3e0915dd6b7f0aab08d9c8beb0d8c64bae2ab8b3c8c6bc53b3bf0db728",
+            ]),
+            (m1, [
+                "9d151eefd8590b89daa6ba6cb74af9275dd051026bb149a452fd84e5e57b5500",
+                "1b54d01a4af5b9d5cc3d86d68d285462b19abc2475222f35c085122be4ba1ffa00ad30f8767b3a82384c6574f024c311e2a481332b08ef7f41797891c1646f48",
+            ]),
+            (&m2, [
+                "9dd2fe4e90409e5da87f53976d7405b0c0cac628fc669a741d50063c557e8f50",
+                "1e88e62226bfca6f9994f1f2d51569e0daf8475a3b0fe61a5300eee46d961376035fe83549ada2b8620fcd7c496ce5b33f0cb9dddc2b6460143b03dabac9fb28",
+            ]),
+            (b"The quick brown fox jumps over the lazy dog", [
+                "3e7dea7f2384b6c5a3d0e24aaa29c05e89ddd762145030ec22c71a6db8b2c1f4",
+                "d2b793a0bb6cb5904828b5b6dcfb443bb8f33efc06ad09368878ae4cdc8245b97e60802469bed1e7c21a64ff0b179a6a1e0bb74d92965450a0adab69162c00fe",
+            ]),
+            (&bytes, [
+                "49873eb283659518adab2b8ca5639ecdc35a470c8d52fc30f1d050843556563b",
+                "a2d416a81674cdd44dbebafba6d41a16f589a76c94464192247d5fd2f56ae726485f06e117731b34a3389574938a4f1fb972b815527690a7791f97cfbf540e32",
+            ]),
+        ];
+
+        for (bytes, expectations) in cases.iter() {
+            check_digest::<[u8; 32], Streebog256Digest>(bytes, &hex_str_to_bytes(expectations[0]));
+            check_digest::<[u8; 64], Streebog512Digest>(bytes, &hex_str_to_bytes(expectations[1]));
+        }
+    }
+
+    #[test]
+    fn whirlpool() {
+        let cases: [(&[u8], &str); 8] = [
+            (b"", "19fa61d75522a4669b44e39c1d2e1726c530232130d407f89afee0964997f7a73e83be698b288febcf88e3e03c4f0757ea8964e59b63d93708b138cc42a66eb3"),
+            (b"a", "8aca2602792aec6f11a67206531fb7d7f0dff59413145e6973c45001d0087b42d11bc645413aeff63a42391a39145a591a92200d560195e53b478584fdae231a"),
+            (b"abc", "4e2448a4c6f486bb16b6562c73b4020bf3043e3a731bce721ae1b303d97e6d4c7181eebdb6c57e277d0e34957114cbd6c797fc9d95d8b582d225292076d4eef5"),
+            (b"message digest", "378c84a4126e2dc6e56dcc7458377aac838d00032230f53ce1f5700c0ffb4d3b8421557659ef55c106b4b52ac5a4aaa692ed920052838f3362e86dbd37a8903e"),
+            (b"abcdefghijklmnopqrstuvwxyz", "f1d754662636ffe92c82ebb9212a484a8d38631ead4238f5442ee13b8054e41b08bf2a9251c30b6a0b8aae86177ab4a6f68f673e7207865d5d9819a3dba4eb3b"),
+            (b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789", "dc37e008cf9ee69bf11f00ed9aba26901dd7c28cdec066cc6af42e40f82f3a1e08eba26629129d8fb7cb57211b9281a65517cc879d7b962142c65f5a7af01467"),
+            (b"12345678901234567890123456789012345678901234567890123456789012345678901234567890", "466ef18babb0154d25b9d38a6414f5c08784372bccb204d6549c4afadb6014294d5bd8df2a6c44e538cd047b2681a51a2c60481e88c5a20b2c2a80cf3a9a083b"),
+            (&[b'a'; 1000000], "0c99005beb57eff50a7cf005560ddf5d29057fd86b20bfd62deca0f1ccea4af51fc15490eddc47af32bb2b66c34ff9ad8c6008ad677f77126953b226e4ed8b01"),
+        ];
+
+        for (bytes, expectation) in cases.iter() {
+            check_digest::<[u8; 64], WhirlpoolDigest>(bytes, &hex_str_to_bytes(expectation));
+        }
+    }
+
+    #[test]
+    fn tiger() {
+        let cases: [(&[u8], &str); 8] = [
+            (b"", "3293ac630c13f0245f92bbb1766e16167a4e58492dde73f3"),
+            (b"a", "77befbef2e7ef8ab2ec8f93bf587a7fc613e247f5f247809"),
+            (b"abc", "2aab1484e8c158f2bfb8c5ff41b57a525129131c957b5f93"),
+            (
+                b"message digest",
+                "d981f8cb78201a950dcf3048751e441c517fca1aa55a29f6",
+            ),
+            (
+                b"abcdefghijklmnopqrstuvwxyz",
+                "1714a472eee57d30040412bfcc55032a0b11602ff37beee9",
+            ),
+            (
+                b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+                "0f7bf9a19b9c58f2b7610df7e84f0ac3a71c631e7b53f78e",
+            ),
+            (
+                b"12345678901234567890123456789012345678901234567890123456789012345678901234567890",
+                "1c14795529fd9f207a958f84c52f11e887fa0cabdfd91bfd",
+            ),
+            (
+                &[b'a'; 1000000],
+                "6db0e2729cbead93d715c6a7d36302e9b3cee0d2bc314b41",
+            ),
+        ];
+
+        for (bytes, expectation) in cases.iter() {
+            check_digest::<[u8; 24], TigerDigest>(bytes, &hex_str_to_bytes(expectation));
+        }
+    }
+
+    #[test]
+    fn tth() {
+        let bytes = (0..11 * 1024).map(|i| (i % 251) as u8).collect::<Vec<u8>>();
+
+        let cases: [(&[u8], &str, usize); 6] = [
+            (b"", "LWPNACQDBZRYXW3VHJVCJ64QBZNGHOHHHZWCLNQ", 1),
+            (b"abc", "ASD4UJSEH5M47PDYB46KBTSQTSGDKLBHYXOMUIA", 1),
+            (&[b'A'; 1024], "L66Q4YVNAFWVS23X2HJIRA5ZJ7WXR3F26RSASFA", 1),
+            (&[b'A'; 1025], "PZMRYHGY6LTBEH63ZWAHDORHSYTLO4LEFUIKHWY", 2),
+            (
+                &bytes[0..5 * 1024 + 7],
+                "DCJ5XYG5JMXURKRVOGMZG7FSCYTHBPFXMXDDTNA",
+                4,
+            ),
+            (&bytes, "QSUL2MCTJ3DPEEXZRY6GRLVFI2OHX2EUSBG3EPA", 5),
+        ];
+
+        for (bytes, expectation, depth) in cases.iter() {
+            assert_eq!(tth::root_base32(bytes), *expectation);
+
+            let tree = tth::HashTree::new(bytes);
+            assert_eq!(tree.root_base32(), *expectation);
+            assert_eq!(tree.root(), tth::root(bytes));
+            assert_eq!(tree.levels().len(), *depth);
+
+            let chunks = bytes.chunks(tth::LEAF_SIZE).collect::<Vec<&[u8]>>();
+            assert_eq!(tree.leaves().len(), chunks.len().max(1));
+            for (index, chunk) in chunks.iter().enumerate() {
+                assert!(tree.verify_leaf(index, chunk));
+                assert!(!tree.verify_leaf(index, &chunk[1..]));
+            }
+            assert!(!tree.verify_leaf(tree.leaves().len(), b""));
+
+            let root = tree.root();
+            let leaf_count = tree.leaves().len();
+            let received = tth::HashTree::from_leaves(tree.leaves().to_vec(), &root).unwrap();
+            assert_eq!(received, tree);
+            for (index, chunk) in chunks.iter().enumerate() {
+                let proof = tree.proof(index).unwrap();
+                assert!(tth::verify_leaf(&root, leaf_count, index, chunk, &proof));
+                assert!(!tth::verify_leaf(
+                    &root,
+                    leaf_count,
+                    index,
+                    &chunk[1..],
+                    &proof
+                ));
+                if let Some((_, shorter)) = proof.split_last() {
+                    assert!(!tth::verify_leaf(&root, leaf_count, index, chunk, shorter));
+                }
+            }
+            assert_eq!(tree.proof(leaf_count), None);
+
+            let mut d = tth::TTHDigest::new();
+            for seg in dice_bytes(bytes) {
+                d.update(seg);
+            }
+            assert_eq!(tth::base32(&d.finalize_reset()), *expectation);
+            d.update(bytes);
+            assert_eq!(tth::base32(&d.finalize()), *expectation);
+        }
+
+        let tree = tth::HashTree::new(&bytes);
+        let root = tree.root();
+        let mut leaves = tree.leaves().to_vec();
+        leaves[3][0] ^= 1;
+        assert_eq!(tth::HashTree::from_leaves(leaves, &root), None);
+        assert_eq!(tth::HashTree::from_leaves(Vec::new(), &root), None);
+
+        let received = tth::HashTree::from_leaves(tree.leaves().to_vec(), &root).unwrap();
+        let mut chunk = bytes[7 * tth::LEAF_SIZE..8 * tth::LEAF_SIZE].to_vec();
+        assert!(received.verify_leaf(7, &chunk));
+        assert!(!received.verify_leaf(6, &chunk));
+        chunk[100] ^= 1;
+        assert!(!received.verify_leaf(7, &chunk));
+
+        let chunk = &bytes[10 * tth::LEAF_SIZE..];
+        let mut proof = tree.proof(10).unwrap();
+        assert_eq!(proof.len(), 2);
+        assert!(tth::verify_leaf(&root, 11, 10, chunk, &proof));
+        assert!(!tth::verify_leaf(&root, 11, 9, chunk, &proof));
+        proof[0][0] ^= 1;
+        assert!(!tth::verify_leaf(&root, 11, 10, chunk, &proof));
+
+        assert_eq!(tth::base32(b""), "");
+        assert_eq!(tth::base32(b"f"), "MY");
+        assert_eq!(tth::base32(b"foobar"), "MZXW6YTBOI");
+    }
+
+    #[test]
+    fn sha512t() {
+        let long = b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu";
+        let inputs: [&[u8]; 3] = [b"", b"abc", long];
+
+        for bytes in inputs.iter() {
+            check_digest::<_, SHA512tDigest<224>>(bytes, &SHA512x224Digest::hash(bytes));
+            check_digest::<_, SHA512tDigest<256>>(bytes, &SHA512x256Digest::hash(bytes));
+        }
+
+        let mut rng = rand::thread_rng();
+        let mut bytes = vec![0u8; 1000];
+        rng.fill_bytes(&mut bytes);
+        check_digest::<_, SHA512tDigest<224>>(&bytes, &SHA512x224Digest::hash(&bytes));
+        check_digest::<_, SHA512tDigest<256>>(&bytes, &SHA512x256Digest::hash(&bytes));
+
+        let expectations: [[&str; 3]; 5] = [
+            ["79", "c5", "55"],
+            ["deca5d803a5cfcbf4191e9fc4bc065e3", "3b273530347747cde5c927ff8d34b6ef", "a90a38af36136d8e18614ea42f5bca19"],
+            ["9896f27c73cdc4ecc8eca3e16f6eeb63afe04b6c0d39276c", "6c4cb5b80909c1f4858dd872ababebce67bc9a3ea8e9866c", "ce6a7d5b2bd17aeab01976d062fdc01b7b97b9b3f35d8160"],
+            ["8c85ac7890758e9fa3acdf6339a10546b16cc40c5346e2289403ab5515fc9110", "e549922e5e47cf602806d2e232230db102b266d503df2121fc8e93ecd347e430", "b8ab88e5700b15fafeb0ef023587bb81d9c8ce74899256152fc10d7b503fdc40"],
+            ["6c46fed4cb277417c5f2d88b19a88a9a010e9e81a24d4a38d818c84a1aa3b88dd115f9550869eb097001fe0e8315b1d6f04124215f095e0be7ca94f99cdc6a", "8c43e4bf1cad93067af1ad632ba38bba0b5673bf0129f01a469224c2d981b8ecaa301facf8e392f97efc5997885a1c90cefba70d81892f40267df4fd6fef9a", "0af75487c3a53b66cc2a0a0eed7ad5ae9ac7d7779e214392e93ed311c9e4a2e3f71e8b7540d846f2f81ddb8e9700a9d369d28ab82101d6334eba15d5c6668a"],
+        ];
+
+        for (i, bytes) in inputs.iter().enumerate() {
+            check_digest::<_, SHA512tDigest<8>>(bytes, &hex_str_to_bytes(expectations[0][i]));
+            check_digest::<_, SHA512tDigest<128>>(bytes, &hex_str_to_bytes(expectations[1][i]));
+            check_digest::<_, SHA512tDigest<192>>(bytes, &hex_str_to_bytes(expectations[2][i]));
+            check_digest::<_, SHA512tDigest<252>>(bytes, &hex_str_to_bytes(expectations[3][i]));
+            check_digest::<_, SHA512tDigest<504>>(bytes, &hex_str_to_bytes(expectations[4][i]));
+        }
+    }
+
+    #[test]
+    fn sha1dc() {
+        let mut rng = rand::thread_rng();
+        for len in [0, 1, 55, 56, 64, 1000].iter() {
+            let mut bytes = vec![0u8; *len];
+            rng.fill_bytes(&mut bytes);
+            let expectation = SHA1Digest::hash(&bytes);
+            assert_eq!(SHA1DCDigest::hash(&bytes), Ok(expectation));
+
+            let mut d = SHA1DCDigest::new().safe_hash(false);
+            for seg in dice_bytes(&bytes) {
+                d.update(seg);
+            }
+            assert_eq!(d.finalize_reset(), Ok(expectation));
+            assert_eq!(d.chain(&bytes).finalize(), Ok(expectation));
+        }
+
+        let unsafe_hash = hex_str_to_bytes("38762cf7f55934b34d179ae6a4c80cadccbb7f0a");
+        let cases = [
+            (
+                "shattered-1.pdf",
+                "16e96b70000dd1e7c85b8368ee197754400e58ec",
+            ),
+            (
+                "shattered-2.pdf",
+                "e1761773e6a35916d99f891b77663e6405313587",
+            ),
+        ];
+
+        for (filename, safe_hash) in cases.iter() {
+            let bytes = read("gen/data/".to_owned() + filename).unwrap();
+            assert_eq!(SHA1Digest::hash(&bytes).to_vec(), unsafe_hash);
+
+            let SHA1Collision { digest } = SHA1DCDigest::hash(&bytes).unwrap_err();
+            assert_eq!(digest.to_vec(), hex_str_to_bytes(safe_hash));
+
+            let mut d = SHA1DCDigest::new().safe_hash(false);
+            for seg in dice_bytes(&bytes) {
+                d.update(seg);
+            }
+            let SHA1Collision { digest } = d.finalize_reset().unwrap_err();
+            assert_eq!(digest.to_vec(), unsafe_hash);
+            let prefix = &bytes[0..192];
+            assert_eq!(d.chain(prefix).finalize(), Ok(SHA1Digest::hash(prefix)));
+        }
+    }
+
+    #[test]
+    fn skein() {
+        let m: Vec<u8> = (0..=255u8).rev().collect();
+
+        check_digest::<_, Skein256<32>>(
+            b"",
+            &hex_str_to_bytes("c8877087da56e072870daa843f176e9453115929094c3a40c463a196c29bf7ba"),
+        );
+        check_digest::<_, Skein256<32>>(
+            &m[0..1],
+            &hex_str_to_bytes("0b98dcd198ea0e50a7a244c444e25c23da30c10fc9a1f270a6637f1f34e67ed2"),
+        );
+        check_digest::<_, Skein512x256Digest>(
+            b"",
+            &hex_str_to_bytes("39ccc4554a8b31853b9de7a1fe638a24cce6b35a55f2431009e18780335d2621"),
+        );
+        check_digest::<_, Skein512x512Digest>(b"", &hex_str_to_bytes("bc5b4c50925519c290cc634277ae3d6257212395cba733bbad37a4af0fa06af41fca7903d06564fea7a2d3730dbdb80c1f85562dfcc070334ea4d1d9e72cba7a"));
+        check_digest::<_, Skein512x512Digest>(&m[0..64], &hex_str_to_bytes("45863ba3be0c4dfc27e75d358496f4ac9a736a505d9313b42b2f5eada79fc17f63861e947afb1d056aa199575ad3f8c9a3cc1780b5e5fa4cae050e989876625b"));
+        check_digest::<_, Skein512x512Digest>(&m[0..128], &hex_str_to_bytes("91cca510c263c4ddd010530a33073309628631f308747e1bcbaa90e451cab92e5188087af4188773a332303e6667a7a210856f742139000071f48e8ba2a5adb7"));
+        check_digest::<_, Skein1024x1024Digest>(b"", &hex_str_to_bytes("0fff9563bb3279289227ac77d319b6fff8d7e9f09da1247b72a0a265cd6d2a62645ad547ed8193db48cff847c06494a03f55666d3b47eb4c20456c9373c86297d630d5578ebd34cb40991578f9f52b18003efa35d3da6553ff35db91b81ab890bec1b189b7f52cb2a783ebb7d823d725b0b4a71f6824e88f68f982eefc6d19c6"));
+
+        let mut rng = rand::thread_rng();
+        let mut bytes = vec![0u8; 1000];
+        rng.fill_bytes(&mut bytes);
+        let expectation = Skein512::<100>::hash(&bytes);
+        check_digest::<_, Skein512<100>>(&bytes, &expectation);
+        assert_ne!(Skein512::<64>::hash(&bytes)[..], expectation[0..64]);
+
+        let key = b"key";
+        let mac = Skein512x256Digest::new_keyed(key).chain(&bytes).finalize();
+        assert_ne!(mac, Skein512x256Digest::hash(&bytes));
+        let mut d = Skein512x256Digest::new_keyed(key);
+        for seg in dice_bytes(&bytes) {
+            d.update(seg);
+        }
+        assert_eq!(d.finalize_reset(), mac);
+        assert_eq!(d.chain(&bytes).finalize(), mac);
+        assert_eq!(
+            Skein512x256Digest::new_keyed(b"").chain(&bytes).finalize(),
+            Skein512x256Digest::hash(&bytes)
+        );
+
+        let key = hex_str_to_bytes("cb41f1706cde09651203c2d0efbaddf8");
+        assert_eq!(
+            Skein256::<32>::new_keyed(&key).finalize().to_vec(),
+            hex_str_to_bytes("886e4efefc15f06aa298963971d7a25398fffe5681c84db39bd00851f64ae29d")
+        );
+
+        let mut block = [0u8; 32];
+        Threefish256::new(&[0; 32], &[0; 16]).encrypt_block(&mut block);
+        assert_eq!(
+            block.to_vec(),
+            hex_str_to_bytes("84da2a1f8beaee947066ae3e3103f1ad536db1f4a1192495116b9f3ce6133fd8")
+        );
+        let mut block = [0u8; 64];
+        Threefish512::new(&[0; 64], &[0; 16]).encrypt_block(&mut block);
+        assert_eq!(block.to_vec(), hex_str_to_bytes("b1a2bbc6ef6025bc40eb3822161f36e375d1bb0aee3186fbd19e47c5d479947b7bc2f8586e35f0cff7e7f03084b0b7b1f1ab3961a580a3e97eb41ea14a6d7bbe"));
+        let mut block = [0u8; 128];
+        Threefish1024::new(&[0; 128], &[0; 16]).encrypt_block(&mut block);
+        assert_eq!(block.to_vec(), hex_str_to_bytes("f05c3d0a3d05b304f785ddc7d1e036015c8aa76e2f217b06c6e1544c0bc1a90df0accb9473c24e0fd54fea68057f43329cb454761d6df5cf7b2e9b3614fbd5a20b2e4760b40603540d82eabc5482c171c832afbe68406bc39500367a592943fa9a5b4a43286ca3c4cf46104b443143d560a4b230488311df4feef7e1dfe8391e"));
+
+        let mut key = [0u8; 128];
+        let mut tweak = [0u8; 16];
+        rng.fill_bytes(&mut key);
+        rng.fill_bytes(&mut tweak);
+        let plaintext = bytes[0..128].to_vec();
+        let cipher = Threefish1024::new(&key, &tweak);
+        let mut block = [0u8; 128];
+        block.copy_from_slice(&plaintext);
+        cipher.encrypt_block(&mut block);
+        assert_ne!(block.to_vec(), plaintext);
+        cipher.decrypt_block(&mut block);
+        assert_eq!(block.to_vec(), plaintext);
+    }
+
+    #[test]
+    fn kangaroo_twelve() {
+        check_xof::<_, TurboSHAKE128Xof>(
+            b"",
+            0,
+            &hex_str_to_bytes("1e415f1c5983aff2169217277d17bb538cd945a397ddec541f1ce41af2c1b74c"),
+        );
+        check_xof::<_, TurboSHAKE256Xof>(b"", 0, &hex_str_to_bytes("367a329dafea871c7802ec67f905ae13c57695dc2c6663c61035f59a18f8e7db11edc0e12e91ea60eb6b32df06dd7f002fbafabb6e13ec1cc20d995547600db0"));
+
+        let ptn = |len: usize| (0..len).map(|i| (i % 251) as u8).collect::<Vec<u8>>();
+
+        let cases: [(Vec<u8>, usize, &str, &str); 17] = [
+            (
+                ptn(0),
+                0,
+                "1ac2d450fc3b4205d19da7bfca1b37513c0803577ac7167f06fe2ce1f0ef39e5",
+                "b23d2e9cea9f4904e02bec06817fc10ce38ce8e93ef4c89e6537076af8646404e3e8b68107b8833a5d30490aa33482353fd4adc7148ecb782855003aaebde4a9",
+            ),
+            (
+                ptn(0),
+                0,
+                "1ac2d450fc3b4205d19da7bfca1b37513c0803577ac7167f06fe2ce1f0ef39e54269c056b8c82e48276038b6d292966cc07a3d4645272e31ff38508139eb0a71",
+                "b23d2e9cea9f4904e02bec06817fc10ce38ce8e93ef4c89e6537076af8646404e3e8b68107b8833a5d30490aa33482353fd4adc7148ecb782855003aaebde4a9b0925319d8ea1e121a609821ec19efea89e6d08daee1662b69c840289f188ba860f55760b61f82114c030c97e5178449608ccd2cd2d919fc7829ff69931ac4d0",
+            ),
+            (
+                ptn(1),
+                0,
+                "2bda92450e8b147f8a7cb629e784a058efca7cf7d8218e02d345dfaa65244a1f",
+                "0d005a194085360217128cf17f91e1f71314efa5564539d444912e3437efa17f82db6f6ffe76e781eaa068bce01f2bbf81eacb983d7230f2fb02834a21b1ddd0",
+            ),
+            (
+                ptn(17),
+                0,
+                "6bf75fa2239198db4772e36478f8e19b0f371205f6a9a93a273f51df37122888",
+                "1ba3c02b1fc514474f06c8979978a9056c8483f4a1b63d0dccefe3a28a2f323e1cdcca40ebf006ac76ef0397152346837b1277d3e7faa9c9653b19075098527b",
+            ),
+            (
+                ptn(17 * 17),
+                0,
+                "0c315ebcdedbf61426de7dcf8fb725d1e74675d7f5327a5067f367b108ecb67c",
+                "de8ccbc63e0f133ebb4416814d4c66f691bbf8b6a61ec0a7700f836b086cb029d54f12ac7159472c72db118c35b4e6aa213c6562caaa9dcc518959e69b10f3ba",
+            ),
+            (
+                ptn(17 * 17 * 17),
+                0,
+                "cb552e2ec77d9910701d578b457ddf772c12e322e4ee7fe417f92c758f0d59d0",
+                "647efb49fe9d717500171b41e7f11bd491544443209997ce1c2530d15eb1ffbb598935ef954528ffc152b1e4d731ee2683680674365cd191d562bae753b84aa5",
+            ),
+            (
+                ptn(17 * 17 * 17 * 17),
+                0,
+                "8701045e22205345ff4dda05555cbb5c3af1a771c2b89baef37db43d9998b9fe",
+                "b06275d284cd1cf205bcbe57dccd3ec1ff6686e3ed15776383e1f2fa3c6ac8f08bf8a162829db1a44b2a43ff83dd89c3cf1ceb61ede659766d5ccf817a62ba8d",
+            ),
+            (
+                ptn(17 * 17 * 17 * 17 * 17),
+                0,
+                "844d610933b1b9963cbdeb5ae3b6b05cc7cbd67ceedf883eb678a0a8e0371682",
+                "9473831d76a4c7bf77ace45b59f1458b1673d64bcd877a7c66b2664aa6dd149e60eab71b5c2bab858c074ded81ddce2b4022b5215935c0d4d19bf511aeeb0772",
+            ),
+            (
+                ptn(17 * 17 * 17 * 17 * 17 * 17),
+                0,
+                "3c390782a8a4e89fa6367f72feaaf13255c8d95878481d3cd8ce85f58e880af8",
+                "0652b740d78c5e1f7c8dcc1777097382768b7ff38f9a7a20f29f413bb1b3045b31a5578f568f911e09cf44746da84224a5266e96a4a535e871324e4f9c7004da",
+            ),
+            (
+                ptn(0),
+                1,
+                "fab658db63e94a246188bf7af69a133045f46ee984c56e3c3328caaf1aa1a583",
+                "9280f5cc39b54a5a594ec63de0bb99371e4609d44bf845c2f5b8c316d72b159811f748f23e3fabbe5c3226ec96c62186df2d33e9df74c5069ceecbb4dd10eff6",
+            ),
+            (
+                vec![0xff],
+                41,
+                "d848c5068ced736f4462159b9867fd4c20b808acc3d5bc48e0b06ba0a3762ec4",
+                "47ef96dd616f200937aa7847e34ec2feae8087e3761dc0f8c1a154f51dc9ccf845d7adbce57ff64b639722c6a1672e3bf5372d87e00aff89be97240756998853",
+            ),
+            (
+                vec![0xff; 3],
+                41 * 41,
+                "c389e5009ae57120854c2e8c64670ac01358cf4c1baf89447a724234dc7ced74",
+                "3b48667a5051c5966c53c5d42b95de451e05584e7806e2fb765eda959074172cb438a9e91dde337c98e9c41bed94c4e0aef431d0b64ef2324f7932caa6f54969",
+            ),
+            (
+                vec![0xff; 7],
+                41 * 41 * 41,
+                "75d2f86a2e644566726b4fbcfc5657b9dbcf070c7b0dca06450ab291d7443bcf",
+                "e0911cc00025e1540831e266d94add9b98712142b80d2629e643aac4efaf5a3a30a88cbf4ac2a91a2432743054fbcc9897670e86ba8cec2fc2ace9c966369724",
+            ),
+            (
+                ptn(8191),
+                0,
+                "1b577636f723643e990cc7d6a659837436fd6a103626600eb8301cd1dbe553d6",
+                "3081434d93a4108d8d8a3305b89682cebedc7ca4ea8a3ce869fbb73cbe4a58eef6f24de38ffc170514c70e7ab2d01f03812616e863d769afb3753193ba045b20",
+            ),
+            (
+                ptn(8192),
+                0,
+                "48f256f6772f9edfb6a8b661ec92dc93b95ebd05a08a17b39ae3490870c926c3",
+                "c6ee8e2ad3200c018ac87aaa031cdac22121b412d07dc6e0dccbb53423747e9a1c18834d99df596cf0cf4b8dfafb7bf02d139d0c9035725adc1a01b7230a41fa",
+            ),
+            (
+                ptn(8192),
+                8189,
+                "3ed12f70fb05ddb58689510ab3e4d23c6c6033849aa01e1d8c220a297fedcd0b",
+                "74e47879f10a9c5d11bd2da7e194fe57e86378bf3c3f7448eff3c576a0f18c5caae0999979512090a7f348af4260d4de3c37f1ecaf8d2c2c96c1d16c64b12496",
+            ),
+            (
+                ptn(8192),
+                8190,
+                "6a7c1b6a5cd0d8c9ca943a4a216cc64604559a2ea45f78570a15253d67ba00ae",
+                "f4b5908b929ffe01e0f79ec2f21243d41a396b2e7303a6af1d6399cd6c7a0a2dd7c4f607e8277f9c9b1cb4ab9ddc59d4b92d1fc7558441f1832c3279a4241b8b",
+            ),
+        ];
+
+        for (bytes, customization, kt128, kt256) in cases.iter() {
+            let customization = ptn(*customization);
+
+            let expectation = hex_str_to_bytes(kt128);
+            let mut output = vec![0u8; expectation.len()];
+            let mut kt = KT128::new(&customization);
+            kt.update(bytes);
+            kt.finalize_reset(&mut output);
+            assert_eq!(output, expectation);
+
+            let kt = dice_bytes(bytes)
+                .iter()
+                .fold(kt.threads(4), |kt, seg| kt.chain(seg));
+            kt.finalize_xof().read(&mut output);
+            assert_eq!(output, expectation);
+
+            let expectation = hex_str_to_bytes(kt256);
+            let mut output = vec![0u8; expectation.len()];
+            let mut kt = KT256::new(&customization);
+            kt.update(bytes);
+            kt.finalize_reset(&mut output);
+            assert_eq!(output, expectation);
+
+            dice_bytes(bytes)
+                .iter()
+                .fold(kt.threads(3), |kt, seg| kt.chain(seg))
+                .finalize(&mut output);
+            assert_eq!(output, expectation);
+        }
+
+        let mut output = vec![0u8; 10032];
+        KT128::new(b"").finalize(&mut output);
+        assert_eq!(
+            output[10000..],
+            hex_str_to_bytes("e8dc563642f7228c84684c898405d3a834799158c079b12880277a1d28e2ff6d")[..]
+        );
+        let mut output = vec![0u8; 10064];
+        KT256::new(b"").finalize(&mut output);
+        assert_eq!(
+            output[10000..],
+            hex_str_to_bytes("ad4a1d718cf950506709a4c33396139b4449041fc79a05d68da35f1e453522e056c64fe94958e7085f2964888259b9932752f3ccd855288efee5fcbb8b563069")[..]
+        );
+
+        let mut short = [0u8; 32];
+        KT128::new(b"").chain(b"abc").finalize(&mut short);
+        let mut reader = TurboSHAKE128Xof::<0x07>::new().chain(b"abc\x00").finalize();
+        let mut output = [0u8; 32];
+        reader.read(&mut output);
+        assert_eq!(output, short);
+    }
+
+    #[test]
+    fn fast_hashes() {
+        fn check_seeded<Res: Into<Vec<u8>>, D: Digest<Res>>(
+            d: D,
+            bytes: &[u8],
+            expectation: &[u8],
+        ) {
+            let mut d = dice_bytes(bytes).iter().fold(d, |d, seg| d.chain(seg));
+            assert_eq!(d.finalize_reset().into(), expectation);
+            assert_eq!(d.chain(bytes).finalize().into(), expectation);
+        }
+
+        let cases: [(usize, [&str; 5]); 11] = [
+            (
+                0,
+                [
+                    "ef46db3751d8e999",
+                    "2d06800538d394c2",
+                    "99aa06d3014798d86001c324468d497f",
+                    "00000000",
+                    "cbf29ce484222325",
+                ],
+            ),
+            (
+                3,
+                [
+                    "e5c7bb4533bc65dd",
+                    "5f4299fc161c9cbb",
+                    "e3b55f57945a17cf5f4299fc161c9cbb",
+                    "92fd4bfa",
+                    "d949aa186c0c4928",
+                ],
+            ),
+            (
+                8,
+                [
+                    "884a173614b81b8d",
+                    "3a1c2d7c85af88f8",
+                    "e1e4432a62217fe4cfd50c61c8bb98c1",
+                    "8a2cbc3b",
+                    "a4dc49e2b28ecb7d",
+                ],
+            ),
+            (
+                16,
+                [
+                    "44b6ef2fb84169f7",
+                    "8355e3a6f61770db",
+                    "72950631827607e2842812cc870dcae2",
+                    "d9c908eb",
+                    "7c84dc9477851775",
+                ],
+            ),
+            (
+                97,
+                [
+                    "c93ec3db0dd47e34",
+                    "e7220282dc4e14f4",
+                    "9b09227e063da5a25b3284a61f91e97d",
+                    "a8c10c84",
+                    "b166f047d5e8469f",
+                ],
+            ),
+            (
+                129,
+                [
+                    "0ba25dfd6e891fcf",
+                    "ec7642b431ba3e5a",
+                    "dd5e74ac6b45f54ebc30b63382b09a3b",
+                    "f514629f",
+                    "3c50fcf90ff13adf",
+                ],
+            ),
+            (
+                240,
+                [
+                    "012947f0da6a27b1",
+                    "375a384d957fe865",
+                    "65b5be86da5540e7c92b68e16f83bbb6",
+                    "9f4f71d6",
+                    "f2f972d8a87c5ad5",
+                ],
+            ),
+            (
+                241,
+                [
+                    "8d643f23bf2808e1",
+                    "02e8cd95421c6d02",
+                    "1da1cb61bcb8a2a102e8cd95421c6d02",
+                    "54fe7516",
+                    "5a384b264b4d2cdf",
+                ],
+            ),
+            (
+                1024,
+                [
+                    "138e26c65048ce29",
+                    "e5d78bafa45b2aa5",
+                    "d0ac1f7b93bf57b9e5d78bafa45b2aa5",
+                    "2af62c0c",
+                    "2233e5feed8da91d",
+                ],
+            ),
+            (
+                1025,
+                [
+                    "cfd73aedd2d6a39d",
+                    "e95c42288f28186e",
+                    "2882ebca04ec915ce95c42288f28186e",
+                    "c8d03add",
+                    "abd8d92da7b63a4b",
+                ],
+            ),
+            (
+                5000,
+                [
+                    "a6833d648fd6a332",
+                    "b418500fc42320ee",
+                    "b92ec02c39d33ce7b418500fc42320ee",
+                    "83f68e3a",
+                    "90c8a516b17c108d",
+                ],
+            ),
+        ];
+
+        for (len, expectations) in cases.iter() {
+            let bytes: Vec<u8> = (0..*len).map(|i| (i % 251) as u8).collect();
+            check_digest::<_, XXH64Digest>(&bytes, &hex_str_to_bytes(expectations[0]));
+            check_digest::<_, XXH3x64Digest>(&bytes, &hex_str_to_bytes(expectations[1]));
+            check_digest::<_, XXH3x128Digest>(&bytes, &hex_str_to_bytes(expectations[2]));
+            check_digest::<_, CRC32CDigest>(&bytes, &hex_str_to_bytes(expectations[3]));
+            check_digest::<_, FNV1a64Digest>(&bytes, &hex_str_to_bytes(expectations[4]));
+        }
+
+        let seed = 0x0123456789abcdef;
+        let cases: [(usize, [&str; 5]); 11] = [
+            (
+                0,
+                [
+                    "51e24c0e9077a48c",
+                    "cc1ca35a1b089c5c",
+                    "a4cb05dbbf09907aaaa287af24a9bb3a",
+                    "89abcdef",
+                    "0123456789abcdef",
+                ],
+            ),
+            (
+                3,
+                [
+                    "786233c2fa006029",
+                    "6db0802353336496",
+                    "59109e2c7580e6e66db0802353336496",
+                    "8c700522",
+                    "f6b570fffabbd0e2",
+                ],
+            ),
+            (
+                8,
+                [
+                    "726ecd68a8b5846d",
+                    "d204fc26419c7d22",
+                    "31b600d25b84b1dd820d57be67d0dd52",
+                    "43ca5b11",
+                    "ec063dce4dee41ff",
+                ],
+            ),
+            (
+                16,
+                [
+                    "4c86f5e612d7e905",
+                    "1dca78f4947ed52c",
+                    "b14d0b33aedc79cbfdb57b7f9152aa8d",
+                    "f1e91f97",
+                    "ed7eab7c11b7f76f",
+                ],
+            ),
+            (
+                97,
+                [
+                    "ea8d04a792c247e6",
+                    "c012c9900ba5c02d",
+                    "0fcf9554e36a729554527190b89d6509",
+                    "3a688f93",
+                    "ecb98decb05f287d",
+                ],
+            ),
+            (
+                129,
+                [
+                    "35dcfd14835a487a",
+                    "75bc8ed192c9cc73",
+                    "759390b24be5ebc21dddad78ad26c26c",
+                    "9d3ec661",
+                    "d1bd3b6c0fa18b9d",
+                ],
+            ),
+            (
+                240,
+                [
+                    "0c636373263d2b72",
+                    "4b1593ee9603224a",
+                    "c7749934436fbf97b8ff7d7b210bf7d8",
+                    "cb34c30a",
+                    "fef2ad84e543486f",
+                ],
+            ),
+            (
+                241,
+                [
+                    "ffa53cd807734af0",
+                    "ace3afe84c9adf7c",
+                    "1ec5ac1612acb5daace3afe84c9adf7c",
+                    "ca3a20df",
+                    "79a577d19154662d",
+                ],
+            ),
+            (
+                1024,
+                [
+                    "d66828a4f19f4e5e",
+                    "db9e8db63b13c98e",
+                    "6eb1074efe8430c2db9e8db63b13c98e",
+                    "e97d48e5",
+                    "803a27025229196f",
+                ],
+            ),
+            (
+                1025,
+                [
+                    "3e0066de29bd9a7b",
+                    "7d6ae2cda98e43c8",
+                    "5566e25f7070b1f47d6ae2cda98e43c8",
+                    "5391c26f",
+                    "0be9c3f19bd64c01",
+                ],
+            ),
+            (
+                5000,
+                [
+                    "6a6262f3ac71e2c1",
+                    "83d09ac4da53e17a",
+                    "80c2deb486140d7983d09ac4da53e17a",
+                    "bb8b54a5",
+                    "5cd84778704cbd67",
+                ],
+            ),
+        ];
+
+        for (len, expectations) in cases.iter() {
+            let bytes: Vec<u8> = (0..*len).map(|i| (i % 251) as u8).collect();
+            check_seeded(
+                XXH64Digest::with_seed(seed),
+                &bytes,
+                &hex_str_to_bytes(expectations[0]),
+            );
+            check_seeded(
+                XXH3x64Digest::with_seed(seed),
+                &bytes,
+                &hex_str_to_bytes(expectations[1]),
+            );
+            check_seeded(
+                XXH3x128Digest::with_seed(seed),
+                &bytes,
+                &hex_str_to_bytes(expectations[2]),
+            );
+            check_seeded(
+                CRC32CDigest::with_seed(seed as u32),
+                &bytes,
+                &hex_str_to_bytes(expectations[3]),
+            );
+            check_seeded(
+                FNV1a64Digest::with_seed(seed),
+                &bytes,
+                &hex_str_to_bytes(expectations[4]),
+            );
+        }
+
+        assert_eq!(CRC32CDigest::hash(b"123456789"), [0xe3, 0x06, 0x92, 0x83]);
+        let crc = CRC32CDigest::hash(b"1234");
+        let seed = u32::from_be_bytes(crc);
+        assert_eq!(
+            CRC32CDigest::with_seed(seed).chain(b"56789").finalize(),
+            [0xe3, 0x06, 0x92, 0x83]
+        );
+    }
+
+    #[test]
+    fn ascon() {
+        let cases = [
+            (
+                0,
+                [
+                    "0b3be5850f2f6b98caf29f8fdea89b64a1fa70aa249b8f839bd53baa304d92b2",
+                    "473d5e6164f58b39dfd84aacdb8ae42ec2d91fed33388ee0d960d9b3993295c6ad77855a5d3b13fe",
+                    "4f50159ef70bb3dad8807e034eaebd44c4fa2cbbc8cf1f05511ab66cdcc529905ca12083fc186ad8",
+                ],
+            ),
+            (
+                1,
+                [
+                    "0728621035af3ed2bca03bf6fde900f9456f5330e4b5ee23e7f6a1e70291bc80",
+                    "51430e0438ecdf642b393630d977625f5f337656ba58ab1e960784ac32a16e0d446405551f546938",
+                    "63fa8ba86382f2d544580f51322d080424b42c556eb74503cd73cf052bb993bd6f5210984c71c9c4",
+                ],
+            ),
+            (
+                7,
+                [
+                    "3e4d273ba69b3b9c53216107e88b75cdbeedbcbf8faf0219c3928ab62b116577",
+                    "7ae562db37212a9acd2673ecfd5b4f1c5cb2e6f64ebf00aa7f6ef8dc82c448d5fe11cd91f4368c37",
+                    "30a89dc47334e56c7d78ee2b72ecf09c2113b2128f04f75bcfa4eb52f491b76286d0cf6500c4b5f3",
+                ],
+            ),
+            (
+                8,
+                [
+                    "b88e497ae8e6fb641b87ef622eb8f2fca0ed95383f7ffebe167acf1099ba764f",
+                    "8d1886f5d3ec4af8d15b44bc62b74da6ea91bc28fb82f9c34079b5ed6e38b6c951803d7dfb3c5e51",
+                    "7c2fc5904cc9ac514902e50747e36f993dbde034cb05587af1432bf81c74b1ec87ecf61797010644",
+                ],
+            ),
+            (
+                9,
+                [
+                    "94269c30e0296e1ec86655041841823efa1927f520fd58c8e9bce6197878c1a6",
+                    "db3013bfbbd132dc1d3152fd955ed48f7cbb675e9ad2a2fecf92b74c957592e0c89959e81c16fd07",
+                    "75ba4455e500e37946c81fddf6a5e168a94f18d1b31c7927d109736a8a33887e3d7e189e569acd85",
+                ],
+            ),
+            (
+                15,
+                [
+                    "6421330df99c05eb715415ee17b455f2674f862ae3cc5badffe43a4a3ed273e1",
+                    "7517d9b0383dc7742e9e1335d97d3f1c5a971416ca4e72bf504e962f80286862733ad8f5e60adcc1",
+                    "7f9836650e75d2203839ef7ff6ea1c0d8060fda4a1639a36dcc815a65e560b240b9695880e4ed725",
+                ],
+            ),
+            (
+                16,
+                [
+                    "3158c1940a2fbadbd68ab661777859b94a689e4efc375911467addd641835c38",
+                    "10bfedc5f6442d3e1d8c324878ce1ddf73b01cafc365589283ac4cbb98e48de3ceda8a41bb0983d5",
+                    "161f6852d12e85ed7811867390be83abb52c9556456eac96a3e51d600d4916656087189e1b6e1b44",
+                ],
+            ),
+            (
+                17,
+                [
+                    "f149e99dd0f429599bb89b8079bf3f4dca3f298efefcf9b1ea16fe84f9b8b6e2",
+                    "233af64f97ca9bd97bae06270571e57215c5cb5ba4038536c5c128da1d3a379ae13da3e54546a149",
+                    "2d50c07d29b374c51a2fd7687a58cce564d396c3613417b833967b1da4270b72825ce31caa64e189",
+                ],
+            ),
+            (
+                63,
+                [
+                    "5072896862f6b9cfe8ef76d80559e156254782a40ac5f64cbf7934ad1f624b30",
+                    "2a4849b4d960678780a24f58d51d3c8155e5dc006021024ba3ac463f242499dee2476355b24dd832",
+                    "35c0a31aa26b7eda15875cc29aaea4e861823971f96ad1862c0f4568d1131aa1dbd41c2d852b1c1b",
+                ],
+            ),
+            (
+                64,
+                [
+                    "a6f241bea5d16405812c06019d9f72d60132bd7c089c60549b2e56bb01c64f48",
+                    "0865c2fa92c71058e79e5c4214f3a1505540411586920536ccee85fbf2940b9f0131385ffe92f15f",
+                    "76e419c68380ba10b891c22d249fa050f85b3cb30a1dbcc5157bae1124c7221e96223ebffe9a0fbe",
+                ],
+            ),
+            (
+                65,
+                [
+                    "bff4fa006fe6feabb5ce9b219492d0d230f4d05f2bac42db7189f441b1e83b53",
+                    "439f3bceb9c9313cf5584dfe9abc803bc5d54df8fa95def24041e8a666a0eace60448f5d4aeb0295",
+                    "39bb7528b1f488b3c55a0eb1ead865233c66c08ad97484e00f1ebb2423f28037dbab81702aa20836",
+                ],
+            ),
+            (
+                1000,
+                [
+                    "6421c35b8df64340f489321b75bb75967ef0ccf4b7ff6f800aacc750508d9e56",
+                    "b568e68dd7675f459d46ad3c80556f9c3f15037b554b0e6bfb0b9e9093cb4c41826c31e516a926b5",
+                    "3b77a1776599c4bfe2b275ec60013f2c6cc8ee0fe2a53f9e2dcb91296ba5ea2ff62068ae6f67076e",
+                ],
+            ),
+        ];
+
+        for (len, expectations) in cases.iter() {
+            let bytes: Vec<u8> = (0..*len).map(|i| (i % 256) as u8).collect();
+            let customization: Vec<u8> = (0..*len % 33).map(|i| (0x10 + i) as u8).collect();
+
+            check_digest::<[u8; 32], AsconHash256Digest>(
+                &bytes,
+                &hex_str_to_bytes(expectations[0]),
+            );
+            check_xof::<AsconReader, AsconXof128>(&bytes, 0, &hex_str_to_bytes(expectations[1]));
+            check_xof::<AsconReader, AsconXof128>(
+                &bytes,
+                16,
+                &hex_str_to_bytes(&expectations[1][32..]),
+            );
+
+            let expectation = hex_str_to_bytes(expectations[2]);
+            let mut output = vec![0u8; expectation.len()];
+
+            AsconCXof128::new(&customization)
+                .chain(&bytes)
+                .finalize()
+                .read(&mut output);
+            assert_eq!(output, expectation);
+
+            let mut cxof = dice_bytes(&bytes)
+                .iter()
+                .fold(AsconCXof128::new(&customization), |x, seg| x.chain(seg));
+            cxof.finalize_reset().read(&mut output);
+            assert_eq!(output, expectation);
+
+            cxof.update(&bytes);
+            let mut reader = cxof.finalize_reset();
+            for chunk in output.chunks_mut(3) {
+                reader.read(chunk);
+            }
+            assert_eq!(output, expectation);
+        }
+    }
+
+    #[test]
+    fn bit_input() {
+        fn check_bits<Res: Into<Vec<u8>>, D: BitDigest<Res>>(
+            bytes: &[u8],
+            bit_len: usize,
+            expectation: &[u8],
+        ) {
+            let whole = bit_len / 8;
+            assert_eq!(D::hash_bits(bytes, bit_len).into(), expectation);
+            assert_eq!(
+                D::new()
+                    .chain(&bytes[0..whole])
+                    .finalize_bits(&bytes[whole..], bit_len % 8)
+                    .into(),
+                expectation
+            );
+
+            let mut d = D::new();
+            for seg in dice_bytes(&bytes[0..whole]) {
+                d.update(seg);
+            }
+            d.update_bits(&bytes[whole..], bit_len % 8);
+            assert_eq!(d.finalize_reset().into(), expectation);
+            d.update_bits(bytes, bit_len);
+            assert_eq!(d.finalize_reset().into(), expectation);
+
+            for split in [0, 1, 3, 7, 9, bit_len / 2, bit_len.saturating_sub(1)] {
+                let split = split.min(bit_len);
+                let middle = (bit_len - split) / 2;
+                let end = bit_len - split - middle;
+                d.update_bits(bit_slice(bytes, 0, split), split);
+                d.update_bits([], 0);
+                d.update_bits(bit_slice(bytes, split, middle), middle);
+                let digest = d.finalize_bits(bit_slice(bytes, split + middle, end), end);
+                assert_eq!(digest.into(), expectation);
+
+                d = D::new();
+                d.update_bits(bit_slice(bytes, 0, split), split);
+                d.update_bits(bit_slice(bytes, split, middle), middle);
+                d.update_bits(bit_slice(bytes, split + middle, end), end);
+                d.update_bits([], 0);
+                assert_eq!(d.finalize_reset().into(), expectation);
+            }
+        }
+
+        fn bit_slice(bytes: &[u8], start: usize, len: usize) -> Vec<u8> {
+            let mut slice = vec![0u8; len.div_ceil(8)];
+            for i in 0..len {
+                let bit = (bytes[(start + i) / 8] >> (7 - (start + i) % 8)) & 1;
+                slice[i / 8] |= bit << (7 - i % 8);
+            }
+            slice
+        }
+
+        let cases: [(usize, [&str; 5]); 9] = [
+            (
+                1,
+                [
+                    "bb6b3e18f0115b57925241676f5b1ae88747b08a",
+                    "d3fe57cb76cdd24e9eb23e7e15684e039c75459beaae100f89712e9d",
+                    "bd4f9e98beb68c6ead3243b1b4c7fed75fa4feaab1f84795cbd8a98676a2a375",
+                    "634aa63038a164ae6c7d48b319f2aca0a107908e548519204c6d72dbeac0fdc3c9246674f98e8fd30221ba986e737d61",
+                    "b4594eb12959fc2e6979b6783554299cc0369f44083a8b0955baefd8830cda22894b0b46c0ed49490e391ad99af856cc1bd96f238c7f2a17cf37aeb7e793395a",
+                ],
+            ),
+            (
+                5,
+                [
+                    "ef292519b5d8f9cf0449ea8a752c241f403579fd",
+                    "16e904bb46e3b325890217884bd13c65f86d8d2ae029469be54e36a5",
+                    "b873d5d6263fc6539cd8bf430d13ed28b2fa3bf62f06a3c23411bb3a60bc70f1",
+                    "83dc9eb59499ba3981c06942d3dfd03e720cf90ea895f0a2dd58a03f2e9e2650e3c6a8ee06d722cf1c16059b0fe034b5",
+                    "a05aad484443d7c68d4589889fac3fc079b805c68045ae3c2487575dbba50a6301cdd5b34f4c0feb2c8449bcb6303e82752203d0e6425656b93743a720d60199",
+                ],
+            ),
+            (
+                446,
+                [
+                    "44897e3ab9e8d356a98f5041f13ce747680a48d5",
+                    "e573bf0766fc7a9d354e0b8ece90bb35c4ba1acac3aab924b65723a7",
+                    "44625825481e529b64f8fd28f0d8da7ba8ee6518390caf559077145157cf7457",
+                    "9f63f569de4ce650a1f85a49f4e058d7c480dd361fa4a1cdb63b1fa8e73cf7abd88c4fbfc8d9f19a0b04526649ee4010",
+                    "231ca6b2e64557d8407090984fe132a18d3beecbab5435123c8b3b9223756795686b215857cb76eed60b37297ce77eff83efa9c7608a8c58aec826422794d7b9",
+                ],
+            ),
+            (
+                447,
+                [
+                    "70c3e8cd24e5d27c60d2908dc11bdbcc09046e39",
+                    "c1ed7f0711b9f6f619bcc95c5d03577d18c8d5a120fa78494e7ae998",
+                    "12f745a06014b2330d46819b0156a67fb964162768a3800634cbd5edb794d1ac",
+                    "13d67c731f74bab8bb5ea76cd6a76919057953aa4b699fa0858573f3feaf599cc2c07621cc551a2d9a5c2d38d2fa836e",
+                    "0f59dbf015debf8b6cb556cbe2d80a50fb1a67792e716a366dbd96feeff99126bd931ec2085a0c0a7adf1b0479c10cb0fcd4c7aad7dc54566e13a072082a02a1",
+                ],
+            ),
+            (
+                449,
+                [
+                    "04f9382a088b192f03a9fbcfda81c488e9eb2fad",
+                    "340f233dd225a29df27f7bab27c63d4b23a2e76928ff69902477f8ee",
+                    "677afc96f93dede106e9e38eec67869030b8e2112262ef27c34cddc78ada5614",
+                    "d59418e1ebbfde8310212737a39758f42ddc8dde7808e60c347fd93d11e4f6a39e160f408f0bb1c97687aec1f388a949",
+                    "44d1733019c3ed7dc3ed16320d4deef2a2b07c27e11b58702b7c4f19c17e71b36b0a511ff5dd6cbee0b44babeba4661ee2013d0aff159e996b5faac1007eea50",
+                ],
+            ),
+            (
+                511,
+                [
+                    "5f91e0d8a308dccd45f6056a215278fddd83bd58",
+                    "eaad42f9244341f703aad00e3caf92e03daa4cd646aa150777dd82e7",
+                    "95564ea27f9e2f93dd7a248d455a1d56b0c72366ff2898ecefd83bef6030a63a",
+                    "4f374fe4a32707a3dc062484ad71e9f89d64de7463ac2b16ad07505b33d37c2f2d63b654af29195b36d0c009346401ed",
+                    "f6022fed04eb45bd3051259f71d9952683cea83c0c7cfd158c3254f2abea467f2fb70e33a39cd2a0fbeecdf6c67caa5cd5c539ddd1b5e8ca202cfdc6e8308668",
+                ],
+            ),
+            (
+                895,
+                [
+                    "ec6c8cc509b64ac40406920fa47169af80d4ebcd",
+                    "536512dd966d56f194a4629db52c56ddabf206c8c18e03a94b4ef43c",
+                    "27bda4e905db597c8e230190a54186e4dd779999c5f2dd394154eead50aee755",
+                    "c436f70fa3ecf737af85fb17e9a6a13569f374bc06fdbd4a1aa5d3e88c87919dcb68f08a074c3f16b2259284e5955860",
+                    "724af57b060fd216ac139145c608a90df5a02cd3a0bfab44fcca3141e5306bd0761e264ebbb7961cd376f5a4b3074d584cdbeacb4c8886dfd404d5344e67a063",
+                ],
+            ),
+            (
+                897,
+                [
+                    "dd9ed6feb9bde5a528534c054d8ed916662ef924",
+                    "4248ae7fe7c693e0bb8df57706d2333674da8e5d806891b708d73002",
+                    "b7159c88077ad246fb46fbb3da756139a9e2bfabc1496cac9b25e63627051387",
+                    "e75a71a806a7ca78850b17791bc635829e43b8c7c92b7061e973af0f7cfed3d2d9c7837427f7570c10d9f17f50d34522",
+                    "8ec19158f4692af784c4f63a862afe14b61fddc29e4abba075c8e6b63357d0f7ca1405aeebc955e9ef3929d1189af078fd0d752386832ecabc54c020395c02bc",
+                ],
+            ),
+            (
+                1021,
+                [
+                    "06fbaa41f5113b71fdf059edacebc1dbee00682e",
+                    "2c09d785f1296b17dc750ca0e56ce939bf7bac3d7a05e7f7f0261ed3",
+                    "128318fa1fa6e0a25fd3e390ba4aff1836d2c668aa49ebcf9802a9abcfaedc64",
+                    "a0614e70c4d3c87995ed4ca7c763cc04cd540dea9638692556ff3884e69cbe123526934526d05e4888cd15b989dfaabd",
+                    "84762e71732f9a0ec1e9ffd5dc145c99ffb7d3cf69bd493ba3b4d5ea52189ca4056c6b08b5832b8590342908d1672420d9ccd298784ea722180adad80a384275",
+                ],
+            ),
+        ];
+
+        let bytes: Vec<u8> = (0..200).map(|i| ((7 * i + 3) % 251) as u8).collect();
+
+        for &(bit_len, expectations) in cases.iter() {
+            let bytes = &bytes[0..bit_len.div_ceil(8)];
+            check_bits::<[u8; 20], SHA1Digest>(bytes, bit_len, &hex_str_to_bytes(expectations[0]));
+            check_bits::<[u8; 28], SHA224Digest>(
+                bytes,
+                bit_len,
+                &hex_str_to_bytes(expectations[1]),
+            );
+            check_bits::<[u8; 32], SHA256Digest>(
+                bytes,
+                bit_len,
+                &hex_str_to_bytes(expectations[2]),
+            );
+            check_bits::<[u8; 48], SHA384Digest>(
+                bytes,
+                bit_len,
+                &hex_str_to_bytes(expectations[3]),
+            );
+            check_bits::<[u8; 64], SHA512Digest>(
+                bytes,
+                bit_len,
+                &hex_str_to_bytes(expectations[4]),
+            );
+            assert_eq!(
+                SHA1DCDigest::hash_bits(bytes, bit_len).unwrap().to_vec(),
+                hex_str_to_bytes(expectations[0])
+            );
+        }
+
+        for bit_len in (0..=1600).step_by(8) {
+            let bytes = &bytes[0..bit_len / 8];
+            assert_eq!(
+                SHA256Digest::hash_bits(bytes, bit_len),
+                SHA256Digest::hash(bytes)
+            );
+            assert_eq!(
+                SHA512Digest::hash_bits(bytes, bit_len),
+                SHA512Digest::hash(bytes)
+            );
+        }
+
+        assert_eq!(
+            SHA1Digest::hash_bits([0x98], 5).to_vec(),
+            hex_str_to_bytes("29826b003b906e660eff4027ce98af3531ac75ba")
+        );
+        assert_eq!(
+            SHA256Digest::hash_bits([0x68], 5).to_vec(),
+            hex_str_to_bytes("d6d3e02a31a84a8caa9718ed6c2057be09db45e7823eb5079ce7a573a3760f95")
+        );
+        assert_eq!(
+            SHA256Digest::hash_bits([0x6f], 5),
+            SHA256Digest::hash_bits([0x68], 5)
+        );
+
+        let vectors: [(usize, usize, &str, &str); 14] = [
+            (
+                1,
+                5,
+                "98",
+                "29826b003b906e660eff4027ce98af3531ac75ba",
+            ),
+            (
+                1,
+                123,
+                "49b2aec2594bbe3a3b117542d94ac880",
+                "6239781e03729919c01955b3ffa8acb60b988340",
+            ),
+            (
+                1,
+                611,
+                "65f932995ba4ce2cb1b4a2e71ae70220aacec8962dd4499cbd7c887a94eaaa101ea5aabc529b4e7e43665a5af2cd03fe678ea6a5005bba3b082204c28b9109f469dac92aaab3aa7c11a1b32ae0",
+                "8c5b2a5ddae5a97fc7f9d85661c672adbf7933d4",
+            ),
+            (
+                224,
+                5,
+                "68",
+                "e3b048552c3c387bcab37f6eb06bb79b96a4aee5ff27f51531a9551c",
+            ),
+            (
+                224,
+                123,
+                "f07006f25a0bea68cd76a29587c28da0",
+                "1b01db6cb4a9e43ded1516beb3db0b87b6d1ea43187462c608137150",
+            ),
+            (
+                256,
+                5,
+                "68",
+                "d6d3e02a31a84a8caa9718ed6c2057be09db45e7823eb5079ce7a573a3760f95",
+            ),
+            (
+                256,
+                123,
+                "be2746c6db52765fdb2f88700f9a7360",
+                "77ec1dc89c821ff2a1279089fa091b35b8cd960bcaf7de01c6a7680756beb972",
+            ),
+            (
+                256,
+                611,
+                "3e740371c810c2b99fc04e804907ef7cf26be28b57cb58a3e2f3c007166e49c12e9ba34c0104069129ea7615642545703a2bd901e16eb0e05deba014ebff6406a07d54364eff742da779b0b3a0",
+                "3e9ad6468bbbad2ac3c2cdc292e018ba5fd70b960cf1679777fce708fdb066e9",
+            ),
+            (
+                384,
+                5,
+                "10",
+                "8d17be79e32b6718e07d8a603eb84ba0478f7fcfd1bb93995f7d1149e09143ac1ffcfc56820e469f3878d957a15a3fe4",
+            ),
+            (
+                384,
+                123,
+                "8bc500c77ceed9879da989107ce0aaa0",
+                "d8c43b38e12e7c42a7c9b810299fd6a770bef30920f17532a898de62c7a07e4293449c0b5fa70109f0783211cfc4bce3",
+            ),
+            (
+                384,
+                1123,
+                "68f501792dea9796767022d93da71679309920fa1012aea357b2b1331d40a1d03c41c240b3c9a75b4892f4c0724b68c875321ab8cfe5023bd375bc0f94bd89fe04f297105d7b82ffc0021aeb1ccb674f5244ea3497de26a4191c5f62e5e9a2d8082f0551f4a5306826e91cc006ce1bf60ff719d42fa521c871cd2394d96ef4468f21966b41f2ba80c26e83a9e0",
+                "5860e8de91c21578bb4174d227898a98e0b45c4c760f009549495614daedc0775d92d11d9f8ce9b064eeac8dafc3a297",
+            ),
+            (
+                512,
+                5,
+                "b0",
+                "d4ee29a9e90985446b913cf1d1376c836f4be2c1cf3cada0720a6bf4857d886a7ecb3c4e4c0fa8c7f95214e41dc1b0d21b22a84cc03bf8ce4845f34dd5bdbad4",
+            ),
+            (
+                512,
+                123,
+                "08ecb52ebae1f7422db62bcd54267080",
+                "ed8dc78e8b01b69750053dbb7a0a9eda0fb9e9d292b1ed715e80a7fe290a4e16664fd913e85854400c5af05e6dad316b7359b43e64f8bec3c1f237119986bbb6",
+            ),
+            (
+                512,
+                1123,
+                "3addec85593216d1619aa02d9756970bfc70ace2744f7c6b2788151028f7b6a2550fd74a7e6e69c2c9b45fc454966dc31d2e10da1f95ce02beb4bf8765574cbd6e8337ef420adc98c15cb6d5e4a0241ba0046d250e510231cac2046c991606ab4ee4145bee2ff4bb123aab498d9d44794f99ccad89a9a1621259eda70a5b6dd4bdd87778c9043b9384f5490680",
+                "32ba76fc30eaa0208aeb50ffb5af1864fdbf17902a4dc0a682c61fcea6d92b783267b21080301837f59de79c6b337db2526f8a0a510e5e53cafed4355fe7c2f1",
+            ),
+        ];
+
+        for &(algorithm, bit_len, message, expectation) in vectors.iter() {
+            let message = hex_str_to_bytes(message);
+            let expectation = hex_str_to_bytes(expectation);
+            match algorithm {
+                1 => {
+                    check_bits::<[u8; 20], SHA1Digest>(&message, bit_len, &expectation);
+                    assert_eq!(
+                        SHA1DCDigest::hash_bits(&message, bit_len).unwrap().to_vec(),
+                        expectation
+                    );
+                }
+                224 => check_bits::<[u8; 28], SHA224Digest>(&message, bit_len, &expectation),
+                256 => check_bits::<[u8; 32], SHA256Digest>(&message, bit_len, &expectation),
+                384 => check_bits::<[u8; 48], SHA384Digest>(&message, bit_len, &expectation),
+                512 => check_bits::<[u8; 64], SHA512Digest>(&message, bit_len, &expectation),
+                _ => unreachable!(),
+            }
+        }
+
+        let mut d = SHA256Digest::new();
+        d.update_bits([0x80], 1);
+        assert_eq!(d.finalize_bits([], 0), SHA256Digest::hash_bits([0x80], 1));
+    }
+
+    #[test]
+    fn hmac() {
+        fn check_hmac<Res: AsRef<[u8]>, D: Digest<Res> + Clone>(
+            key: &[u8],
+            bytes: &[u8],
+            expectation: &[u8],
+        ) {
+            let len = expectation.len();
+            assert_eq!(&Hmac::<D>::mac(key, bytes).as_ref()[0..len], expectation);
+
+            let mut h1 = Hmac::<D>::new(key);
+            for seg in dice_bytes(bytes) {
+                h1.update(seg);
+            }
+            assert_eq!(&h1.finalize_reset().as_ref()[0..len], expectation);
+            h1.update(bytes);
+            assert_eq!(&h1.finalize_reset().as_ref()[0..len], expectation);
+
+            let h2 = dice_bytes(bytes)
+                .iter()
+                .fold(Hmac::<D>::new(key), |h, seg| h.chain(seg));
+            let tag = h2.clone().finalize().as_ref().to_vec();
+            assert_eq!(&tag[0..len], expectation);
+            assert!(h2.clone().verify(&tag));
+            assert!(!h2.clone().verify(&tag[0..tag.len() - 1]));
+
+            let mut forged = tag.clone();
+            forged[0] ^= 1;
+            assert!(!h2.verify(&forged));
+
+            let mut h3 = Hmac::<D>::new(key).chain(bytes);
+            assert!(h3.verify_reset(&tag));
+            h3.update(bytes);
+            assert!(h3.verify_reset(&tag));
+        }
+
+        let rfc4231 = [
+            (
+                vec![0x0b; 20],
+                b"Hi There".to_vec(),
+                [
+                    "896fb1128abbdf196832107cd49df33f47b4b1169912ba4f53684b22",
+                    "b0344c61d8db38535ca8afceaf0bf12b881dc200c9833da726e9376c2e32cff7",
+                    "afd03944d84895626b0825f4ab46907f15f9dadbe4101ec682aa034c7cebc59cfaea9ea9076ede7f4af152e8b2fa9cb6",
+                    "87aa7cdea5ef619d4ff0b4241a1d6cb02379f4e2ce4ec2787ad0b30545e17cdedaa833b7d6b8a702038b274eaea3f4e4be9d914eeb61f1702e696c203a126854",
+                ],
+            ),
+            (
+                b"Jefe".to_vec(),
+                b"what do ya want for nothing?".to_vec(),
+                [
+                    "a30e01098bc6dbbf45690f3a7e9e6d0f8bbea2a39e6148008fd05e44",
+                    "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843",
+                    "af45d2e376484031617f78d2b58a6b1b9c7ef464f5a01b47e42ec3736322445e8e2240ca5e69e2c78b3239ecfab21649",
+                    "164b7a7bfcf819e2e395fbe73b56e0a387bd64222e831fd610270cd7ea2505549758bf75c05a994a6d034f65f8f0e6fdcaeab1a34d4a6b4b636e070a38bce737",
+                ],
+            ),
+            (
+                vec![0xaa; 20],
+                vec![0xdd; 50],
+                [
+                    "7fb3cb3588c6c1f6ffa9694d7d6ad2649365b0c1f65d69d1ec8333ea",
+                    "773ea91e36800e46854db8ebd09181a72959098b3ef8c122d9635514ced565fe",
+                    "88062608d3e6ad8a0aa2ace014c8a86f0aa635d947ac9febe83ef4e55966144b2a5ab39dc13814b94e3ab6e101a34f27",
+                    "fa73b0089d56a284efb0f0756c890be9b1b5dbdd8ee81a3655f83e33b2279d39bf3e848279a722c806b485a47e67c807b946a337bee8942674278859e13292fb",
+                ],
+            ),
+            (
+                (1..=25).collect(),
+                vec![0xcd; 50],
+                [
+                    "6c11506874013cac6a2abc1bb382627cec6a90d86efc012de7afec5a",
+                    "82558a389a443c0ea4cc819899f2083a85f0faa3e578f8077a2e3ff46729665b",
+                    "3e8a69b7783c25851933ab6290af6ca77a9981480850009cc5577c6e1f573b4e6801dd23c4a7d679ccf8a386c674cffb",
+                    "b0ba465637458c6990e5a8c5f61d4af7e576d97ff94b872de76f8050361ee3dba91ca5c11aa25eb4d679275cc5788063a5f19741120c4f2de2adebeb10a298dd",
+                ],
+            ),
+            (
+                vec![0x0c; 20],
+                b"Test With Truncation".to_vec(),
+                [
+                    "0e2aea68a90c8d37c988bcdb9fca6fa8",
+                    "a3b6167473100ee06e0c796c2955552b",
+                    "3abf34c3503b2a23a46efc619baef897",
+                    "415fad6271580a531d4179bc891d87a6",
+                ],
+            ),
+            (
+                vec![0xaa; 131],
+                b"Test Using Larger Than Block-Size Key - Hash Key First".to_vec(),
+                [
+                    "95e9a0db962095adaebe9b2d6f0dbce2d499f112f2d2b7273fa6870e",
+                    "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54",
+                    "4ece084485813e9088d2c63a041bc5b44f9ef1012a2b588f3cd11f05033ac4c60c2ef6ab4030fe8296248df163f44952",
+                    "80b24263c7c1a3ebb71493c1dd7be8b49b46d1f41b4aeec1121b013783f8f3526b56d037e05f2598bd0fd2215d6a1e5295e64f73f63f0aec8b915a985d786598",
+                ],
+            ),
+            (
+                vec![0xaa; 131],
+                b"This is a test using a larger than block-size key and a larger than block-size data. The key needs to be hashed before being used by the HMAC algorithm.".to_vec(),
+                [
+                    "3a854166ac5d9f023f54d517d0b39dbd946770db9c2b95c9f6f565d1",
+                    "9b09ffa71b942fcb27635fbcd5b0e944bfdc63644f0713938a7f51535c3a35e2",
+                    "6617178e941f020d351e2f254e8fd32c602420feb0b8fb9adccebb82461e99c5a678cc31e799176d3860e6110c46523e",
+                    "e37b6a775dc87dbaa4dfa9f96e5e3ffddebd71f8867289865df5a32d20cdc944b6022cac3c4982b10d5eeb55c3e4de15134676fb6de0446065c97440fa8c6a58",
+                ],
+            ),
+        ];
+
+        for (key, bytes, expectations) in rfc4231.iter() {
+            check_hmac::<[u8; 28], SHA224Digest>(key, bytes, &hex_str_to_bytes(expectations[0]));
+            check_hmac::<[u8; 32], SHA256Digest>(key, bytes, &hex_str_to_bytes(expectations[1]));
+            check_hmac::<[u8; 48], SHA384Digest>(key, bytes, &hex_str_to_bytes(expectations[2]));
+            check_hmac::<[u8; 64], SHA512Digest>(key, bytes, &hex_str_to_bytes(expectations[3]));
+        }
+
+        let rfc2202 = [
+            (
+                vec![0x0b; 16],
+                vec![0x0b; 20],
+                b"Hi There".to_vec(),
+                [
+                    "9294727a3638bb1c13f48ef8158bfc9d",
+                    "b617318655057264e28bc0b6fb378c8ef146be00",
+                ],
+            ),
+            (
+                b"Jefe".to_vec(),
+                b"Jefe".to_vec(),
+                b"what do ya want for nothing?".to_vec(),
+                [
+                    "750c783e6ab0b503eaa86e310a5db738",
+                    "effcdf6ae5eb2fa2d27416d5f184df9c259a7c79",
+                ],
+            ),
+            (
+                vec![0xaa; 16],
+                vec![0xaa; 20],
+                vec![0xdd; 50],
+                [
+                    "56be34521d144c88dbb8c733f0e8b3f6",
+                    "125d7342b9ac11cd91a39af48aa17b4f63f175d3",
+                ],
+            ),
+            (
+                (1..=25).collect(),
+                (1..=25).collect(),
+                vec![0xcd; 50],
+                [
+                    "697eaf0aca3a3aea3a75164746ffaa79",
+                    "4c9007f4026250c6bc8414f9bf50c86c2d7235da",
+                ],
+            ),
+            (
+                vec![0x0c; 16],
+                vec![0x0c; 20],
+                b"Test With Truncation".to_vec(),
+                [
+                    "56461ef2342edc00f9bab995690efd4c",
+                    "4c1a03424b55e07fe7f27be1d58bb9324a9a5a04",
+                ],
+            ),
+            (
+                vec![0xaa; 80],
+                vec![0xaa; 80],
+                b"Test Using Larger Than Block-Size Key - Hash Key First".to_vec(),
+                [
+                    "6b1ab7fe4bd7bf8f0b62e6ce61b9d0cd",
+                    "aa4ae5e15272d00e95705637ce8a3b55ed402112",
+                ],
+            ),
+            (
+                vec![0xaa; 80],
+                vec![0xaa; 80],
+                b"Test Using Larger Than Block-Size Key and Larger Than One Block-Size Data"
+                    .to_vec(),
+                [
+                    "6f630fad67cda0ee1fb1f562db3aa53e",
+                    "e8e99d0f45237d786d6bbaa7965c7808bbff1a91",
+                ],
+            ),
+        ];
+
+        for (md5_key, sha1_key, bytes, expectations) in rfc2202.iter() {
+            check_hmac::<[u8; 16], MD5Digest>(md5_key, bytes, &hex_str_to_bytes(expectations[0]));
+            check_hmac::<[u8; 20], SHA1Digest>(sha1_key, bytes, &hex_str_to_bytes(expectations[1]));
+        }
+
+        let (key, bytes, _) = &rfc4231[6];
+        check_hmac::<[u8; 32], SHA3_256Digest>(
+            key,
+            bytes,
+            &hex_str_to_bytes("65c5b06d4c3de32a7aef8763261e49adb6e2293ec8e7c61e8de61701fc63e123"),
+        );
+        check_hmac::<[u8; 64], SHA3_512Digest>(
+            key,
+            bytes,
+            &hex_str_to_bytes("38a456a004bd10d32c9ab8336684112862c3db61adcca31829355eaf46fd5c73d06a1f0d13fec9a652fb3811b577b1b1d1b9789f97ae5b83c6f44dfcf1d67eba"),
+        );
+        check_hmac::<[u8; 32], SHA512x256Digest>(
+            key,
+            bytes,
+            &hex_str_to_bytes("6ea83f8e7315072c0bdaa33b93a26fc1659974637a9db8a887d06c05a7f35a66"),
+        );
+        check_hmac::<[u8; 64], Blake2b512Digest>(
+            key,
+            bytes,
+            &hex_str_to_bytes("ab347980a64b5e825dd10e7d32fd43a01a8e6dea267ab9ad7d913524526618925311afbcb0c49519cbebdd709540a8d725fb911ac2aee9b2a3aa43d796123393"),
+        );
+        check_hmac::<[u8; 32], Blake2s256Digest>(
+            key,
+            bytes,
+            &hex_str_to_bytes("cb60f6a791f140bf8aa2e51ff358cdb2cc5c0333045b7fb77aba7ab3b0cfb237"),
+        );
+    }
+
+    #[test]
+    fn hkdf() {
+        fn check_hkdf<D: Digest<Res> + Clone, Res: AsRef<[u8]>>(
+            salt: &[u8],
+            ikm: &[u8],
+            info: &[u8],
+            prk: &str,
+            okm: &str,
+        ) {
+            let prk = hex_str_to_bytes(prk);
+            let okm = hex_str_to_bytes(okm);
+            assert_eq!(hkdf::extract::<D, Res>(salt, ikm).as_ref(), &prk[..]);
+
+            let mut output = vec![0u8; okm.len()];
+            hkdf::expand::<D, Res>(&prk, info, &mut output).unwrap();
+            assert_eq!(output, okm);
+
+            output.fill(0);
+            hkdf::derive::<D, Res>(salt, ikm, info, &mut output).unwrap();
+            assert_eq!(output, okm);
+        }
+
+        let salt: Vec<u8> = (0x00..=0x0c).collect();
+        let info: Vec<u8> = (0xf0..=0xf9).collect();
+        let long_salt: Vec<u8> = (0x60..=0xaf).collect();
+        let long_ikm: Vec<u8> = (0x00..=0x4f).collect();
+        let long_info: Vec<u8> = (0xb0..=0xff).collect();
+
+        check_hkdf::<SHA256Digest, _>(
+            &salt,
+            &[0x0b; 22],
+            &info,
+            "077709362c2e32df0ddc3f0dc47bba6390b6c73bb50f9c3122ec844ad7c2b3e5",
+            "3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf34007208d5b887185865",
+        );
+        check_hkdf::<SHA256Digest, _>(
+            &long_salt,
+            &long_ikm,
+            &long_info,
+            "06a6b88c5853361a06104c9ceb35b45cef760014904671014a193f40c15fc244",
+            "b11e398dc80327a1c8e7f78c596a49344f012eda2d4efad8a050cc4c19afa97c59045a99cac7827271cb41c65e590e09da3275600c2f09b8367793a9aca3db71cc30c58179ec3e87c14c01d5c1f3434f1d87",
+        );
+        check_hkdf::<SHA256Digest, _>(
+            &[],
+            &[0x0b; 22],
+            &[],
+            "19ef24a32c717b167f33a91d6f648bdf96596776afdb6377ac434c1c293ccb04",
+            "8da4e775a563c18f715f802a063c5a31b8a11f5c5ee1879ec3454e5f3c738d2d9d201395faa4b61a96c8",
+        );
+        check_hkdf::<SHA1Digest, _>(
+            &salt,
+            &[0x0b; 11],
+            &info,
+            "9b6c18c432a7bf8f0e71c8eb88f4b30baa2ba243",
+            "085a01ea1b10f36933068b56efa5ad81a4f14b822f5b091568a9cdd4f155fda2c22e422478d305f3f896",
+        );
+        check_hkdf::<SHA1Digest, _>(
+            &long_salt,
+            &long_ikm,
+            &long_info,
+            "8adae09a2a307059478d309b26c4115a224cfaf6",
+            "0bd770a74d1160f7c9f12cd5912a06ebff6adcae899d92191fe4305673ba2ffe8fa3f1a4e5ad79f3f334b3b202b2173c486ea37ce3d397ed034c7f9dfeb15c5e927336d0441f4c4300e2cff0d0900b52d3b4",
+        );
+        check_hkdf::<SHA1Digest, _>(
+            &[],
+            &[0x0b; 22],
+            &[],
+            "da8c8a73c7fa77288ec6f5e7c297786aa0d32d01",
+            "0ac1af7002b3d761d1e55298da9d0506b9ae52057220a306e07b6b87e8df21d0ea00033de03984d34918",
+        );
+        check_hkdf::<SHA1Digest, _>(
+            &[0; 20],
+            &[0x0c; 22],
+            &[],
+            "2adccada18779e7c2077ad2eb19d3f3e731385dd",
+            "2c91117204d745f3500d636a62f64f0ab3bae548aa53d423b0d1f27ebba6f5e5673a081d70cce7acfc48",
+        );
+        check_hkdf::<SHA384Digest, _>(
+            b"salt",
+            b"ikm",
+            b"info",
+            "d2be1d1d8a6a32a6e02ff57e1a1d79658aed17eac0a36729c8b1324e90a18fda759f02e3ee851fa84057188bd107f282",
+            "8a4904829f7acb5fe62bfbce3ed1a2d9428bdcba65d4db11e7471f3b7ab9eaffb058cfdad0c509f3ccd90685666fa8b2b2f6091d3ee2045886335d2ca8524330e16c90401b1abee7254ae0c6b5b131af1911199e43bcd263f5441c7f63e41578237236b6",
+        );
+        check_hkdf::<SHA512Digest, _>(
+            b"salt",
+            b"ikm",
+            b"info",
+            "56cc15664b2dcaa57ae910751a1c03a80768a7abb0d4bf491d99d7949bc2c3a77d423e8b8c91379f35b9966744d12fbb4440f42bbaa0159fc78cd635983cb506",
+            "f8666bd3fdd88840c947614272dd065c71c541b0de03ff738644dffc3facec646317f3819f453c7f0c4b4fb70680b07f020c3e26ce190895b104a4f8d4770f076ab726fd6ad78fc6cfd7a5faa507fb5f45a3b0594b286d665b4d31fe8b1325a2010263e3",
+        );
+
+        let mut output = vec![0u8; 255 * 32];
+        assert_eq!(
+            hkdf::derive::<SHA256Digest, _>(b"salt", b"ikm", b"info", &mut output),
+            Ok(())
+        );
+        let mut output = vec![0u8; 255 * 32 + 1];
+        assert_eq!(
+            hkdf::derive::<SHA256Digest, _>(b"salt", b"ikm", b"info", &mut output),
+            Err(hkdf::HkdfError::OutputTooLong)
+        );
+        let mut output = vec![0u8; 255 * 48 + 1];
+        assert_eq!(
+            hkdf::expand::<SHA384Digest, _>(&[0; 48], b"info", &mut output),
+            Err(hkdf::HkdfError::OutputTooLong)
+        );
+
+        fn check_output_size<Res: AsRef<[u8]>, D: Digest<Res>>() {
+            assert_eq!(D::OUTPUT_SIZE, D::hash(b"abc").as_ref().len());
+        }
+
+        check_output_size::<_, MD5Digest>();
+        check_output_size::<_, SHA224Digest>();
+        check_output_size::<_, SHA512tDigest<252>>();
+        check_output_size::<_, SHA3_384Digest>();
+        check_output_size::<_, Blake2b<20>>();
+        check_output_size::<_, Skein512x256Digest>();
+        check_output_size::<_, WhirlpoolDigest>();
+        check_output_size::<_, CRC32CDigest>();
+        check_output_size::<_, XXH3x128Digest>();
+        assert_eq!(<SHA1DCDigest as Digest<_>>::OUTPUT_SIZE, 20);
+    }
+
+    #[test]
+    fn pbkdf2() {
+        fn check_pbkdf2<D: MidstateDigest<Res>, Res: AsRef<[u8]>>(
+            password: &[u8],
+            salt: &[u8],
+            iterations: u32,
+            expectation: &str,
+        ) {
+            let expectation = hex_str_to_bytes(expectation);
+            let mut output = vec![0u8; expectation.len()];
+            pbkdf2::derive::<D, Res>(password, salt, iterations, &mut output).unwrap();
+            assert_eq!(output, expectation);
+        }
+
+        check_pbkdf2::<SHA1Digest, _>(
+            b"password",
+            b"salt",
+            1,
+            "0c60c80f961f0e71f3a9b524af6012062fe037a6",
+        );
+        check_pbkdf2::<SHA1Digest, _>(
+            b"password",
+            b"salt",
+            2,
+            "ea6c014dc72d6f8ccd1ed92ace1d41f0d8de8957",
+        );
+        check_pbkdf2::<SHA1Digest, _>(
+            b"password",
+            b"salt",
+            4096,
+            "4b007901b765489abead49d926f721d065a429c1",
+        );
+        check_pbkdf2::<SHA1Digest, _>(
+            b"passwordPASSWORDpassword",
+            b"saltSALTsaltSALTsaltSALTsaltSALTsalt",
+            4096,
+            "3d2eec4fe41c849b80c8d83662c0e44a8b291a964cf2f07038",
+        );
+        check_pbkdf2::<SHA1Digest, _>(
+            b"pass\0word",
+            b"sa\0lt",
+            4096,
+            "56fa6aa75548099dcc37d7f03425e0c3",
+        );
+
+        check_pbkdf2::<SHA256Digest, _>(
+            b"passwd",
+            b"salt",
+            1,
+            "55ac046e56e3089fec1691c22544b605f94185216dde0465e68b9d57c20dacbc49ca9cccf179b645991664b39d77ef317c71b845b1e30bd509112041d3a19783",
+        );
+        check_pbkdf2::<SHA256Digest, _>(
+            b"Password",
+            b"NaCl",
+            80000,
+            "4ddcd8f60b98be21830cee5ef22701f9641a4418d04c0414aeff08876b34ab56a1d425a1225833549adb841b51c9b3176a272bdebba1d078478f62b397f33c8d",
+        );
+
+        check_pbkdf2::<SHA224Digest, _>(
+            b"password",
+            b"salt",
+            10,
+            "ae79fcd9e9144f7294b020432b6946b9f290bd544b61885ca8093775711b257d9dd9ba77b423c031",
+        );
+        check_pbkdf2::<SHA384Digest, _>(
+            b"password",
+            b"salt",
+            10,
+            "e03f8ca570b98475a9bcd7f73442f3990c3ec87f8815478954ceb62ac2f3d709891aadcb5f5c9485c13e79e20a46a146715b0231c7053a060af9a52a",
+        );
+        check_pbkdf2::<SHA512Digest, _>(
+            b"password",
+            b"salt",
+            1000,
+            "afe6c5530785b6cc6b1c6453384731bd5ee432ee549fd42fb6695779ad8a1c5bf59de69c48f774efc4007d5298f9033c0241d5ab69305e7b64eceeb8d834cfec6afdec3c1c23982a121f2d4be008889378a49a0dfb104f0d2856e38f44271cdaf6de4341",
+        );
+        check_pbkdf2::<SHA512Digest, _>(
+            &[b'x'; 200],
+            b"salt",
+            2,
+            "87c9db0b7c12b94bec5039ae603028ec5076234596401c3a2899f16dfe93479f2c3471672af152683f243d595ed1a7f3430ec13fda297c99e3989ea03d53f1cd",
+        );
+        check_pbkdf2::<WhirlpoolDigest, _>(
+            b"password",
+            b"salt",
+            1,
+            "7e25009bf8afade8ab33911d331b5b3e987fc7c3e2d5fdb3f33c183e837c357850a75eb8baad2c05b1e3bc7068c2a2d5c0f3e586f401610ad02f525c8fcf2cbd1d409cde583991caa0e815ef33c9696f79d78dae186106bb106184ffb16fbee641906edd",
+        );
+        check_pbkdf2::<WhirlpoolDigest, _>(
+            b"password",
+            b"salt",
+            2,
+            "110b2e4266f03c334f6085bf421a68d6976a2f767e0bb6041a9c9315ec0d249fc8cb5fac1f9f3b87dbb98e9b4b220dfe0d6b55f88109dd558c30f0a0356f7d9fdb61caa5c983cfd07fb4c6da6188094bba860ed19b21ab990f14af6ddec4968bdc19fbcc",
+        );
+        check_pbkdf2::<WhirlpoolDigest, _>(
+            b"password",
+            b"salt",
+            4096,
+            "4f4c0307915b7e3f948daaf41ee7805cd2967513a3be6975a7cce782402598e6bd950c5051ea0c8185beba487b13eb93f5a93b8e2e1e7535643f00dd7c39cad1d704f81b559091d7c5fa8b50410b257eb11478cefe8f3d4d234860f6847c890b2a875dc6",
+        );
+        let salt: Vec<u8> = (0..200).map(|i| i as u8).collect();
+        check_pbkdf2::<SHA256Digest, _>(
+            b"password",
+            &salt,
+            3,
+            "b30e02445e1f9b34c114f1012db5f271a0235fb2ce7ad5960038a1f788458411720c0cbd784fd36f",
+        );
+
+        assert_eq!(
+            pbkdf2::derive::<SHA256Digest, _>(b"password", b"salt", 0, &mut [0u8; 32]),
+            Err(Pbkdf2Error::InvalidIterations)
+        );
+    }
+
+    #[test]
+    fn scrypt() {
+        let cases = [
+            (
+                "",
+                "",
+                16,
+                1,
+                1,
+                "77d6576238657b203b19ca42c18a0497f16b4844e3074ae8dfdffa3fede21442fcd0069ded0948f8326a753a0fc81f17e8d3e0fb2e0d3628cf35e20c38d18906",
+            ),
+            (
+                "password",
+                "NaCl",
+                1024,
+                8,
+                16,
+                "fdbabe1c9d3472007856e7190d01e9fe7c6ad7cbc8237830e77376634b3731622eaf30d92e22a3886ff109279d9830dac727afb94a83ee6d8360cbdfa2cc0640",
+            ),
+            (
+                "pleaseletmein",
+                "SodiumChloride",
+                16384,
+                8,
+                1,
+                "7023bdcb3afd7348461c06cd81fd38ebfda8fbba904f8e3ea9b543f6545da1f2d5432955613f0fcf62d49705242a9af9e61e85dc0d651e40dfcf017b45575887",
+            ),
+        ];
+
+        for &(password, salt, n, r, p, expectation) in cases.iter() {
+            let expectation = hex_str_to_bytes(expectation);
+            let scrypt = Scrypt::new(n, r, p).unwrap();
+            let mut output = vec![0u8; expectation.len()];
+
+            scrypt.derive(password.as_bytes(), salt.as_bytes(), &mut output);
+            assert_eq!(output, expectation);
+
+            output.fill(0);
+            scrypt
+                .threads(3)
+                .derive(password.as_bytes(), salt.as_bytes(), &mut output);
+            assert_eq!(output, expectation);
+        }
+
+        let encoded =
+            "$scrypt$ln=8,r=2,p=3$c2FsdHlzYWx0$NmQOog6fVqKNxshMpulwttgghLKkMvE6dZwQaR+mrJI";
+        let scrypt = Scrypt::new(256, 2, 3).unwrap();
+        assert_eq!(scrypt.encode(b"hunter2", b"saltysalt", 32), encoded);
+        assert_eq!(Scrypt::decode(encoded).unwrap().0, scrypt);
+        assert_eq!(Scrypt::verify(b"hunter2", encoded), Ok(true));
+        assert_eq!(Scrypt::verify(b"hunter3", encoded), Ok(false));
+
+        let phc = PhcString::parse(encoded).unwrap();
+        assert_eq!(phc.id, "scrypt");
+        assert_eq!(phc.version, None);
+        assert_eq!(phc.param("ln"), Some("8"));
+        assert_eq!(phc.param("p"), Some("3"));
+        assert_eq!(phc.salt, b"saltysalt");
+        assert_eq!(phc.to_string(), encoded);
+
+        assert_eq!(
+            Scrypt::verify(b"hunter2", "$scrypt$ln=8,r=2$c2FsdHlzYWx0$NmQO"),
+            Err(ScryptError::Phc(PhcError::MissingParameter))
+        );
+        assert_eq!(
+            Scrypt::verify(b"hunter2", "$scrypt$ln=x,r=2,p=3$c2FsdHlzYWx0$NmQO"),
+            Err(ScryptError::Phc(PhcError::InvalidParameter))
+        );
+        assert_eq!(
+            Scrypt::verify(b"hunter2", "$argon2id$ln=8,r=2,p=3$c2FsdHlzYWx0$NmQO"),
+            Err(ScryptError::Phc(PhcError::UnsupportedAlgorithm))
+        );
+        assert_eq!(
+            Scrypt::verify(b"hunter2", "$scrypt$ln=8,r=2,p=3$c2FsdHlzYWx0$NmQ!"),
+            Err(ScryptError::Phc(PhcError::InvalidBase64))
+        );
+        assert_eq!(
+            Scrypt::verify(b"hunter2", "scrypt$ln=8,r=2,p=3$c2FsdHlzYWx0$NmQO"),
+            Err(ScryptError::Phc(PhcError::InvalidFormat))
+        );
+        assert_eq!(
+            Scrypt::verify(b"hunter2", "$scrypt$ln=0,r=2,p=3$c2FsdHlzYWx0$NmQO"),
+            Err(ScryptError::InvalidCost)
+        );
+        assert_eq!(
+            Scrypt::verify(b"hunter2", "$scrypt$ln=4,r=1,p=1$c2FsdHNhbHQ$"),
+            Err(ScryptError::InvalidHashLength)
+        );
+        assert_eq!(
+            Scrypt::verify(
+                b"hunter2",
+                "$scrypt$ln=8,r=2,p=3$c2FsdHlzYWx0$NmQOog6fVqKNxshMpulw"
+            ),
+            Err(ScryptError::InvalidHashLength)
+        );
+        assert_eq!(
+            Scrypt::verify(b"hunter2", "$scrypt$ln=63,r=8,p=1$c2FsdHlzYWx0$NmQO"),
+            Err(ScryptError::InvalidCost)
+        );
+        assert_eq!(
+            Scrypt::verify(b"hunter2", "$scrypt$ln=40,r=8,p=1$c2FsdHlzYWx0$NmQO"),
+            Err(ScryptError::MemoryLimitExceeded)
+        );
+        assert_eq!(scrypt.memory(), 128 * 2 * 256 + 128 * 2 * 3);
+        assert_eq!(
+            Scrypt::verify_with_max_memory(b"hunter2", encoded, scrypt.memory() - 1),
+            Err(ScryptError::MemoryLimitExceeded)
+        );
+        assert_eq!(
+            Scrypt::verify_with_max_memory(b"hunter2", encoded, scrypt.memory()),
+            Ok(true)
+        );
+
+        assert_eq!(Scrypt::new(0, 8, 1), Err(ScryptError::InvalidCost));
+        assert_eq!(Scrypt::new(1, 8, 1), Err(ScryptError::InvalidCost));
+        assert_eq!(Scrypt::new(1000, 8, 1), Err(ScryptError::InvalidCost));
+        assert_eq!(Scrypt::new(1 << 16, 1, 1), Err(ScryptError::InvalidCost));
+        assert!(Scrypt::new(1 << 15, 1, 1).is_ok());
+        assert_eq!(Scrypt::new(16, 0, 1), Err(ScryptError::InvalidBlockSize));
+        assert_eq!(Scrypt::new(16, 8, 0), Err(ScryptError::InvalidParallelism));
+        assert_eq!(Scrypt::new(1 << 63, 8, 1), Err(ScryptError::InvalidCost));
+        assert_eq!(
+            Scrypt::new(16, 1 << 15, 1 << 15),
+            Err(ScryptError::InvalidParallelism)
+        );
+
+        for len in 0..20 {
+            let bytes: Vec<u8> = (0..len).map(|i| (37 * i + 11) as u8).collect();
+            assert_eq!(phc::decode_base64(&phc::base64(&bytes)), Ok(bytes));
+        }
+        assert_eq!(phc::decode_base64("A"), Err(PhcError::InvalidBase64));
+        assert_eq!(phc::decode_base64("AB"), Err(PhcError::InvalidBase64));
+    }
+
+    #[test]
+    fn argon2() {
+        let password = [0x01u8; 32];
+        let salt = [0x02u8; 16];
+        let secret = [0x03u8; 8];
+        let associated_data = [0x04u8; 12];
+
+        let cases = [
+            (
+                Variant::Argon2d,
+                "512b391b6f1162975371d30919734294f868e3be3984f3c1a13a4db9fabe4acb",
+            ),
+            (
+                Variant::Argon2i,
+                "c814d9d1dc7f37aa13f0d77f2494bda1c8de6b016dd388d29952a4c4672b6ce8",
+            ),
+            (
+                Variant::Argon2id,
+                "0d640df58d78766c08c037a34a8b53c9d01ef0452d75b65eb52520e96b01e659",
+            ),
+        ];
+
+        for &(variant, expected) in cases.iter() {
+            for &threads in [1, 3, 4].iter() {
+                let argon2 = Argon2::new(variant, 32, 3, 4)
+                    .unwrap()
+                    .threads(threads)
+                    .secret(&secret)
+                    .associated_data(&associated_data);
+                let mut tag = [0u8; 32];
+                argon2.derive(&password, &salt, &mut tag).unwrap();
+                assert_eq!(tag.to_vec(), hex_str_to_bytes(expected));
+            }
+        }
+
+        let cases = [
+            (
+                Variant::Argon2id,
+                65,
+                2,
+                2,
+                "66d8d5673b66954b5e9ba66ab02c59802bafe61e45c7cb145a5c6d4a423c4da34bc736c11fadf0bae2374b30a8b3b83747117795a4c6de79a892a5ad3d1b49bb498c1af3198361cca89fd82f9dc0732e3adedf5ccf25e0d3c7a53ca1db67c50faab78998",
+            ),
+            (
+                Variant::Argon2i,
+                1000,
+                2,
+                3,
+                "07b2413f296beea72d848f1f9ee2b9bc8b19b108f3e80c8622c10137dd2b6298e442991d48ccdcf517ac67e53465b346651b953286c713dc1e052523dae5b227",
+            ),
+            (Variant::Argon2d, 256, 1, 1, "c6814ce3"),
+        ];
+
+        for &(variant, m, t, p, expected) in cases.iter() {
+            let expected = hex_str_to_bytes(expected);
+            let mut tag = vec![0u8; expected.len()];
+            Argon2::new(variant, m, t, p)
+                .unwrap()
+                .threads(2)
+                .derive(b"password", b"somesalt", &mut tag)
+                .unwrap();
+            assert_eq!(tag, expected);
+        }
+
+        let encoded =
+            "$argon2id$v=19$m=64,t=3,p=2$c29tZXNhbHQ$LZ4BMGumBSTGtS1OEMjd3MCEoQSjKBEivYLb5aLErOs";
+        let argon2 = Argon2::new(Variant::Argon2id, 64, 3, 2).unwrap();
+        assert_eq!(argon2.encode(b"hunter2", b"somesalt", 32).unwrap(), encoded);
+        assert_eq!(Argon2::verify(b"hunter2", encoded), Ok(true));
+        assert_eq!(Argon2::verify(b"hunter3", encoded), Ok(false));
+
+        let (decoded, phc) = Argon2::decode(encoded).unwrap();
+        assert_eq!(decoded, argon2);
+        assert_eq!(phc.salt, b"somesalt");
+
+        let keyed = argon2.clone().secret(b"pepper");
+        let encoded = keyed.encode(b"hunter2", b"somesalt", 16).unwrap();
+        assert_eq!(Argon2::verify(b"hunter2", &encoded), Ok(false));
+        assert_eq!(
+            Argon2::verify_with_secret(b"hunter2", b"pepper", &encoded),
+            Ok(true)
+        );
+
+        let bound = argon2.clone().associated_data(b"context");
+        let encoded = bound.encode(b"hunter2", b"somesalt", 16).unwrap();
+        assert!(encoded.starts_with("$argon2id$v=19$m=64,t=3,p=2,data=Y29udGV4dA$"));
+        assert_eq!(Argon2::decode(&encoded).unwrap().0, bound);
+        assert_eq!(Argon2::verify(b"hunter2", &encoded), Ok(true));
+        assert_eq!(Argon2::verify(b"hunter3", &encoded), Ok(false));
+        let tampered = encoded.replace("data=Y29udGV4dA", "data=Y29udGV4dB");
+        assert_eq!(
+            Argon2::verify(b"hunter2", &tampered),
+            Err(Argon2Error::Phc(PhcError::InvalidBase64))
+        );
+        let tampered = encoded.replace("data=Y29udGV4dA", "data=Y29udGV4dQ");
+        assert_eq!(Argon2::verify(b"hunter2", &tampered), Ok(false));
+
+        assert_eq!(argon2.memory(), 64 * 1024);
+        assert_eq!(
+            Argon2::new(Variant::Argon2id, 70, 1, 2).unwrap().memory(),
+            64 * 1024
+        );
+        let encoded = argon2.encode(b"hunter2", b"somesalt", 16).unwrap();
+        assert_eq!(
+            Argon2::verify_with_max_memory(b"hunter2", b"", &encoded, 64 * 1024 - 1),
+            Err(Argon2Error::MemoryLimitExceeded)
+        );
+        assert_eq!(
+            Argon2::verify_with_max_memory(b"hunter2", b"", &encoded, 64 * 1024),
+            Ok(true)
+        );
+        assert_eq!(
+            Argon2::verify(
+                b"hunter2",
+                "$argon2id$v=19$m=4294967295,t=3,p=2$c29tZXNhbHQ$AAAAAA"
+            ),
+            Err(Argon2Error::MemoryLimitExceeded)
+        );
+        assert_eq!(
+            Argon2::verify(
+                b"hunter2",
+                "$argon2id$v=19$m=64,t=4294967295,p=2$c29tZXNhbHQ$AAAAAA"
+            ),
+            Err(Argon2Error::PassLimitExceeded)
+        );
+
+        assert_eq!(
+            Argon2::new(Variant::Argon2id, 31, 1, 4),
+            Err(Argon2Error::InvalidMemory)
+        );
+        assert_eq!(
+            Argon2::new(Variant::Argon2id, 32, 0, 4),
+            Err(Argon2Error::InvalidPasses)
+        );
+        assert_eq!(
+            Argon2::new(Variant::Argon2id, 32, 1, 0),
+            Err(Argon2Error::InvalidParallelism)
+        );
+        assert_eq!(
+            argon2.derive(b"hunter2", b"salt", &mut [0u8; 32]),
+            Err(Argon2Error::InvalidSaltLength)
+        );
+        assert_eq!(
+            argon2.derive(b"hunter2", b"somesalt", &mut [0u8; 3]),
+            Err(Argon2Error::InvalidOutputLength)
+        );
+        assert_eq!(
+            Argon2::verify(b"hunter2", "$argon2x$v=19$m=64,t=3,p=2$c29tZXNhbHQ$AAAAAA"),
+            Err(Argon2Error::Phc(PhcError::UnsupportedAlgorithm))
+        );
+        assert_eq!(
+            Argon2::verify(b"hunter2", "$argon2id$v=16$m=64,t=3,p=2$c29tZXNhbHQ$AAAAAA"),
+            Err(Argon2Error::Phc(PhcError::InvalidParameter))
+        );
+        assert_eq!(
+            Argon2::verify(b"hunter2", "$argon2id$v=19$m=64,p=2$c29tZXNhbHQ$AAAAAA"),
+            Err(Argon2Error::Phc(PhcError::MissingParameter))
+        );
+    }
+
+    #[test]
+    fn sha_crypt() {
+        let cases = [
+            ("Hello world!", "saltstring", None),
+            ("Hello world!", "saltstringsaltstring", Some(10000)),
+            ("This is just a test", "toolongsaltstring", Some(5000)),
+            (
+                "a very much longer text to encrypt.  This one even stretches over morethan one line.",
+                "anotherlongsaltstring",
+                Some(1400),
+            ),
+            (
+                "we have a short salt string but not a short password",
+                "short",
+                Some(77777),
+            ),
+            ("a short string", "asaltof16chars..", Some(123456)),
+            ("the minimum number is still observed", "roundstoolow", Some(10)),
+        ];
+
+        let sha256_expected = [
+            "$5$saltstring$5B8vYYiY.CVt1RlTTf8KbXBH3hsxY/GNooZaBBGWEc5",
+            "$5$rounds=10000$saltstringsaltst$3xv.VbSHBb41AL9AvLeujZkZRBAwqFMz2.opqey6IcA",
+            "$5$rounds=5000$toolongsaltstrin$Un/5jzAHMgOGZ5.mWJpuVolil07guHPvOW8mGRcvxa5",
+            "$5$rounds=1400$anotherlongsalts$Rx.j8H.h8HjEDGomFU8bDkXm3XIUnzyxf12oP84Bnq1",
+            "$5$rounds=77777$short$JiO1O3ZpDAxGJeaDIuqCoEFysAe1mZNJRs3pw0KQRd/",
+            "$5$rounds=123456$asaltof16chars..$gP3VQ/6X7UUEW3HkBn2w1/Ptq2jxPyzV/cZKmF/wJvD",
+            "$5$rounds=1000$roundstoolow$yfvwcWrQ8l/K0DAWyuPMDNHpIVlTQebY9l/gL972bIC",
+        ];
+
+        let sha512_expected = [
+            "$6$saltstring$svn8UoSVapNtMuq1ukKS4tPQd8iKwSMHWjl/O817G3uBnIFNjnQJuesI68u4OTLiBFdcbYEdFCoEOfaS35inz1",
+            "$6$rounds=10000$saltstringsaltst$OW1/O6BYHV6BcXZu8QVeXbDWra3Oeqh0sbHbbMCVNSnCM/UrjmM0Dp8vOuZeHBy/YTBmSK6H9qs/y3RnOaw5v.",
+            "$6$rounds=5000$toolongsaltstrin$lQ8jolhgVRVhY4b5pZKaysCLi0QBxGoNeKQzQ3glMhwllF7oGDZxUhx1yxdYcz/e1JSbq3y6JMxxl8audkUEm0",
+            "$6$rounds=1400$anotherlongsalts$POfYwTEok97VWcjxIiSOjiykti.o/pQs.wPvMxQ6Fm7I6IoYN3CmLs66x9t0oSwbtEW7o7UmJEiDwGqd8p4ur1",
+            "$6$rounds=77777$short$WuQyW2YR.hBNpjjRhpYD/ifIw05xdfeEyQoMxIXbkvr0gge1a1x3yRULJ5CCaUeOxFmtlcGZelFl5CxtgfiAc0",
+            "$6$rounds=123456$asaltof16chars..$BtCwjqMJGx5hrJhZywWvt0RLE8uZ4oPwcelCjmw2kSYu.Ec6ycULevoBK25fs2xXgMNrCzIMVcgEJAstJeonj1",
+            "$6$rounds=1000$roundstoolow$kUMsbe306n21p9R.FRkW3IGn.S9NPN0x50YhH1xhLsPuWGsUSklZt58jaTfF4ZEQpyUNGc0dqbpBYYBaHHrsX.",
+        ];
+
+        for (&(password, salt, rounds), (sha256, sha512)) in cases
+            .iter()
+            .zip(sha256_expected.iter().zip(sha512_expected.iter()))
+        {
+            assert_eq!(
+                sha_crypt::sha256_crypt(password.as_bytes(), salt, rounds),
+                *sha256
+            );
+            assert_eq!(
+                sha_crypt::sha512_crypt(password.as_bytes(), salt, rounds),
+                *sha512
+            );
+            assert_eq!(sha_crypt::verify(password.as_bytes(), sha256), Ok(true));
+            assert_eq!(sha_crypt::verify(password.as_bytes(), sha512), Ok(true));
+            assert_eq!(sha_crypt::verify(b"wrong", sha256), Ok(false));
+        }
+
+        assert_eq!(
+            sha_crypt::verify(
+                b"the minimum number is still observed",
+                "$5$rounds=10$roundstoolow$yfvwcWrQ8l/K0DAWyuPMDNHpIVlTQebY9l/gL972bIC"
+            ),
+            Ok(false)
+        );
+        assert_eq!(
+            sha_crypt::sha256_crypt(b"x", "abc$def", None),
+            sha_crypt::sha256_crypt(b"x", "abc", None)
+        );
+        assert_eq!(
+            sha_crypt::verify(b"x", "$1$abc$def"),
+            Err(ShaCryptError::UnsupportedAlgorithm)
+        );
+        assert_eq!(
+            sha_crypt::verify(b"x", "$5$rounds=abc$abc$def"),
+            Err(ShaCryptError::InvalidRounds)
+        );
+        assert_eq!(
+            sha_crypt::verify(b"x", "$5$abc"),
+            Err(ShaCryptError::InvalidFormat)
+        );
+        assert_eq!(
+            sha_crypt::verify(b"x", "5$abc$def"),
+            Err(ShaCryptError::InvalidFormat)
+        );
+    }
 }
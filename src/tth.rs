@@ -0,0 +1,240 @@
+use crate::digest::Digest;
+use crate::tiger::TigerDigest;
+
+pub const LEAF_SIZE: usize = 1024;
+
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+fn leaf(bytes: &[u8]) -> [u8; 24] {
+    TigerDigest::new().chain([0x00]).chain(bytes).finalize()
+}
+
+fn node(left: &[u8; 24], right: &[u8; 24]) -> [u8; 24] {
+    TigerDigest::new()
+        .chain([0x01])
+        .chain(left)
+        .chain(right)
+        .finalize()
+}
+
+pub fn base32(bytes: &[u8]) -> String {
+    let mut encoded = String::with_capacity((8 * bytes.len()).div_ceil(5));
+    let mut buffer = 0u16;
+    let mut bits = 0;
+
+    for &byte in bytes {
+        buffer = (buffer << 8) | byte as u16;
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            encoded.push(BASE32_ALPHABET[(buffer >> bits) as usize & 0x1f] as char);
+        }
+    }
+
+    if bits > 0 {
+        encoded.push(BASE32_ALPHABET[(buffer << (5 - bits)) as usize & 0x1f] as char);
+    }
+
+    encoded
+}
+
+pub fn root(bytes: impl AsRef<[u8]>) -> [u8; 24] {
+    TTHDigest::hash(bytes)
+}
+
+pub fn root_base32(bytes: impl AsRef<[u8]>) -> String {
+    base32(&root(bytes))
+}
+
+pub fn verify_leaf(
+    root: &[u8; 24],
+    leaf_count: usize,
+    index: usize,
+    bytes: &[u8],
+    proof: &[[u8; 24]],
+) -> bool {
+    if index >= leaf_count || bytes.len() > LEAF_SIZE {
+        return false;
+    }
+
+    let mut hash = leaf(bytes);
+    let mut index = index;
+    let mut len = leaf_count;
+    let mut proof = proof.iter();
+
+    while len > 1 {
+        if index % 2 == 1 || index + 1 < len {
+            let sibling = match proof.next() {
+                Some(sibling) => sibling,
+                None => return false,
+            };
+            hash = if index % 2 == 1 {
+                node(sibling, &hash)
+            } else {
+                node(&hash, sibling)
+            };
+        }
+        index /= 2;
+        len = len.div_ceil(2);
+    }
+
+    proof.next().is_none() && hash == *root
+}
+
+#[derive(Clone)]
+pub struct TTHDigest {
+    buffer: Vec<u8>,
+    stack: Vec<(u32, [u8; 24])>,
+}
+
+impl TTHDigest {
+    fn push(&mut self, hash: [u8; 24]) {
+        let mut entry = (0, hash);
+        while let Some(&(level, left)) = self.stack.last() {
+            if level != entry.0 {
+                break;
+            }
+            self.stack.pop();
+            entry = (level + 1, node(&left, &entry.1));
+        }
+        self.stack.push(entry);
+    }
+}
+
+impl Digest<[u8; 24]> for TTHDigest {
+    const BLOCK_SIZE: usize = LEAF_SIZE;
+    const OUTPUT_SIZE: usize = 24;
+
+    fn new() -> Self {
+        Self {
+            buffer: Vec::with_capacity(LEAF_SIZE),
+            stack: Vec::new(),
+        }
+    }
+
+    fn hash(bytes: impl AsRef<[u8]>) -> [u8; 24] {
+        Self::new().chain(bytes).finalize()
+    }
+
+    fn update(&mut self, bytes: impl AsRef<[u8]>) {
+        let mut bytes = bytes.as_ref();
+
+        while !bytes.is_empty() {
+            if self.buffer.len() == LEAF_SIZE {
+                let hash = leaf(&self.buffer);
+                self.push(hash);
+                self.buffer.clear();
+            }
+
+            let len = (LEAF_SIZE - self.buffer.len()).min(bytes.len());
+            self.buffer.extend_from_slice(&bytes[0..len]);
+            bytes = &bytes[len..];
+        }
+    }
+
+    fn chain(mut self, bytes: impl AsRef<[u8]>) -> Self {
+        self.update(bytes);
+        self
+    }
+
+    fn finalize(mut self) -> [u8; 24] {
+        self.finalize_reset()
+    }
+
+    fn finalize_reset(&mut self) -> [u8; 24] {
+        let mut root = leaf(&self.buffer);
+        while let Some((_, left)) = self.stack.pop() {
+            root = node(&left, &root);
+        }
+        self.buffer.clear();
+        root
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HashTree {
+    levels: Vec<Vec<[u8; 24]>>,
+}
+
+impl HashTree {
+    pub fn new(bytes: impl AsRef<[u8]>) -> Self {
+        let bytes = bytes.as_ref();
+
+        let leaves = if bytes.is_empty() {
+            vec![leaf(bytes)]
+        } else {
+            bytes.chunks(LEAF_SIZE).map(leaf).collect()
+        };
+
+        Self::build(leaves)
+    }
+
+    pub fn from_leaves(leaves: Vec<[u8; 24]>, root: &[u8; 24]) -> Option<Self> {
+        if leaves.is_empty() {
+            return None;
+        }
+
+        let tree = Self::build(leaves);
+        if tree.root() != *root {
+            return None;
+        }
+        Some(tree)
+    }
+
+    fn build(leaves: Vec<[u8; 24]>) -> Self {
+        let mut levels = vec![leaves];
+        while levels[levels.len() - 1].len() > 1 {
+            let level = levels[levels.len() - 1]
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => node(left, right),
+                    [single] => *single,
+                    _ => unreachable!(),
+                })
+                .collect();
+            levels.push(level);
+        }
+
+        Self { levels }
+    }
+
+    pub fn root(&self) -> [u8; 24] {
+        self.levels[self.levels.len() - 1][0]
+    }
+
+    pub fn root_base32(&self) -> String {
+        base32(&self.root())
+    }
+
+    pub fn levels(&self) -> &[Vec<[u8; 24]>] {
+        &self.levels
+    }
+
+    pub fn leaves(&self) -> &[[u8; 24]] {
+        &self.levels[0]
+    }
+
+    pub fn verify_leaf(&self, index: usize, bytes: &[u8]) -> bool {
+        bytes.len() <= LEAF_SIZE
+            && self
+                .leaves()
+                .get(index)
+                .is_some_and(|&hash| hash == leaf(bytes))
+    }
+
+    pub fn proof(&self, index: usize) -> Option<Vec<[u8; 24]>> {
+        if index >= self.leaves().len() {
+            return None;
+        }
+
+        let mut index = index;
+        let mut proof = Vec::new();
+        for level in self.levels[0..self.levels.len() - 1].iter() {
+            if let Some(&sibling) = level.get(index ^ 1) {
+                proof.push(sibling);
+            }
+            index /= 2;
+        }
+        Some(proof)
+    }
+}
@@ -0,0 +1,54 @@
+use crate::cshake::{absorb_encoded_string, right_encode, CShake};
+use crate::digest::XofReader;
+use crate::sponge::SpongeReader;
+use core::mem::replace;
+
+#[derive(Clone)]
+pub struct TupleHash<const RATE: usize> {
+    cshake: CShake<RATE>,
+    initial: CShake<RATE>,
+}
+
+impl<const RATE: usize> TupleHash<RATE> {
+    pub fn new(customization: &[u8]) -> Self {
+        let cshake = CShake::new(b"TupleHash", customization);
+        Self {
+            cshake: cshake.clone(),
+            initial: cshake,
+        }
+    }
+
+    pub fn update(&mut self, element: impl AsRef<[u8]>) {
+        absorb_encoded_string(self.cshake.sponge_mut(), element.as_ref());
+    }
+
+    pub fn chain(mut self, element: impl AsRef<[u8]>) -> Self {
+        self.update(element);
+        self
+    }
+
+    pub fn finalize(self, bytes: &mut [u8]) {
+        Self::squeeze(self.cshake, bytes);
+    }
+
+    pub fn finalize_reset(&mut self, bytes: &mut [u8]) {
+        Self::squeeze(replace(&mut self.cshake, self.initial.clone()), bytes);
+    }
+
+    pub fn finalize_xof(self) -> SpongeReader<RATE> {
+        self.cshake.chain(right_encode(0).as_ref()).finalize()
+    }
+
+    pub fn finalize_xof_reset(&mut self) -> SpongeReader<RATE> {
+        let cshake = replace(&mut self.cshake, self.initial.clone());
+        cshake.chain(right_encode(0).as_ref()).finalize()
+    }
+
+    fn squeeze(cshake: CShake<RATE>, bytes: &mut [u8]) {
+        let encoded_len = right_encode(8 * bytes.len() as u64);
+        cshake.chain(encoded_len.as_ref()).finalize().read(bytes);
+    }
+}
+
+pub type TupleHash128 = TupleHash<168>;
+pub type TupleHash256 = TupleHash<136>;
@@ -0,0 +1,526 @@
+use crate::digest::Digest;
+use core::convert::TryInto;
+
+const PRIME32_1: u64 = 0x9e3779b1;
+const PRIME32_2: u64 = 0x85ebca77;
+const PRIME32_3: u64 = 0xc2b2ae3d;
+
+const PRIME64_1: u64 = 0x9e3779b185ebca87;
+const PRIME64_2: u64 = 0xc2b2ae3d27d4eb4f;
+const PRIME64_3: u64 = 0x165667b19e3779f9;
+const PRIME64_4: u64 = 0x85ebca77c2b2ae63;
+const PRIME64_5: u64 = 0x27d4eb2f165667c5;
+
+const PRIME_MX1: u64 = 0x165667919e3779f9;
+const PRIME_MX2: u64 = 0x9fb21c651e98df25;
+
+const SECRET: [u8; 192] = [
+    0xb8, 0xfe, 0x6c, 0x39, 0x23, 0xa4, 0x4b, 0xbe, 0x7c, 0x01, 0x81, 0x2c, 0xf7, 0x21, 0xad, 0x1c,
+    0xde, 0xd4, 0x6d, 0xe9, 0x83, 0x90, 0x97, 0xdb, 0x72, 0x40, 0xa4, 0xa4, 0xb7, 0xb3, 0x67, 0x1f,
+    0xcb, 0x79, 0xe6, 0x4e, 0xcc, 0xc0, 0xe5, 0x78, 0x82, 0x5a, 0xd0, 0x7d, 0xcc, 0xff, 0x72, 0x21,
+    0xb8, 0x08, 0x46, 0x74, 0xf7, 0x43, 0x24, 0x8e, 0xe0, 0x35, 0x90, 0xe6, 0x81, 0x3a, 0x26, 0x4c,
+    0x3c, 0x28, 0x52, 0xbb, 0x91, 0xc3, 0x00, 0xcb, 0x88, 0xd0, 0x65, 0x8b, 0x1b, 0x53, 0x2e, 0xa3,
+    0x71, 0x64, 0x48, 0x97, 0xa2, 0x0d, 0xf9, 0x4e, 0x38, 0x19, 0xef, 0x46, 0xa9, 0xde, 0xac, 0xd8,
+    0xa8, 0xfa, 0x76, 0x3f, 0xe3, 0x9c, 0x34, 0x3f, 0xf9, 0xdc, 0xbb, 0xc7, 0xc7, 0x0b, 0x4f, 0x1d,
+    0x8a, 0x51, 0xe0, 0x4b, 0xcd, 0xb4, 0x59, 0x31, 0xc8, 0x9f, 0x7e, 0xc9, 0xd9, 0x78, 0x73, 0x64,
+    0xea, 0xc5, 0xac, 0x83, 0x34, 0xd3, 0xeb, 0xc3, 0xc5, 0x81, 0xa0, 0xff, 0xfa, 0x13, 0x63, 0xeb,
+    0x17, 0x0d, 0xdd, 0x51, 0xb7, 0xf0, 0xda, 0x49, 0xd3, 0x16, 0x55, 0x26, 0x29, 0xd4, 0x68, 0x9e,
+    0x2b, 0x16, 0xbe, 0x58, 0x7d, 0x47, 0xa1, 0xfc, 0x8f, 0xf8, 0xb8, 0xd1, 0x7a, 0xd0, 0x31, 0xce,
+    0x45, 0xcb, 0x3a, 0x8f, 0x95, 0x16, 0x04, 0x28, 0xaf, 0xd7, 0xfb, 0xca, 0xbb, 0x4b, 0x40, 0x7e,
+];
+
+const STRIPE_BYTES: usize = 64;
+const XXH3_BLOCK_BYTES: usize = 1024;
+
+fn read32(bytes: &[u8], offset: usize) -> u64 {
+    u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap()) as u64
+}
+
+fn read64(bytes: &[u8], offset: usize) -> u64 {
+    u64::from_le_bytes(bytes[offset..offset + 8].try_into().unwrap())
+}
+
+fn xxh64_round(accumulator: u64, input: u64) -> u64 {
+    accumulator
+        .wrapping_add(input.wrapping_mul(PRIME64_2))
+        .rotate_left(31)
+        .wrapping_mul(PRIME64_1)
+}
+
+fn xxh64_avalanche(mut h: u64) -> u64 {
+    h ^= h >> 33;
+    h = h.wrapping_mul(PRIME64_2);
+    h ^= h >> 29;
+    h = h.wrapping_mul(PRIME64_3);
+    h ^ (h >> 32)
+}
+
+#[derive(Clone)]
+pub struct XXH64Digest {
+    seed: u64,
+    accumulators: [u64; 4],
+    block: [u8; 32],
+    offset: usize,
+    len: u64,
+}
+
+impl XXH64Digest {
+    pub fn with_seed(seed: u64) -> Self {
+        Self {
+            seed,
+            accumulators: [
+                seed.wrapping_add(PRIME64_1).wrapping_add(PRIME64_2),
+                seed.wrapping_add(PRIME64_2),
+                seed,
+                seed.wrapping_sub(PRIME64_1),
+            ],
+            block: [0u8; 32],
+            offset: 0,
+            len: 0,
+        }
+    }
+
+    fn compress(&mut self) {
+        for (i, accumulator) in self.accumulators.iter_mut().enumerate() {
+            *accumulator = xxh64_round(*accumulator, read64(&self.block, 8 * i));
+        }
+    }
+}
+
+impl Digest<[u8; 8]> for XXH64Digest {
+    const BLOCK_SIZE: usize = 32;
+    const OUTPUT_SIZE: usize = 8;
+
+    fn new() -> Self {
+        Self::with_seed(0)
+    }
+
+    fn hash(bytes: impl AsRef<[u8]>) -> [u8; 8] {
+        Self::new().chain(bytes).finalize()
+    }
+
+    fn update(&mut self, bytes: impl AsRef<[u8]>) {
+        let mut bytes = bytes.as_ref();
+        self.len += bytes.len() as u64;
+
+        while !bytes.is_empty() {
+            let len = (32 - self.offset).min(bytes.len());
+            self.block[self.offset..self.offset + len].copy_from_slice(&bytes[0..len]);
+            self.offset += len;
+            bytes = &bytes[len..];
+
+            if self.offset == 32 {
+                self.compress();
+                self.offset = 0;
+            }
+        }
+    }
+
+    fn chain(mut self, bytes: impl AsRef<[u8]>) -> Self {
+        self.update(bytes);
+        self
+    }
+
+    fn finalize(mut self) -> [u8; 8] {
+        self.finalize_reset()
+    }
+
+    fn finalize_reset(&mut self) -> [u8; 8] {
+        let [v1, v2, v3, v4] = self.accumulators;
+
+        let mut h = if self.len >= 32 {
+            let mut h = v1
+                .rotate_left(1)
+                .wrapping_add(v2.rotate_left(7))
+                .wrapping_add(v3.rotate_left(12))
+                .wrapping_add(v4.rotate_left(18));
+            for &v in self.accumulators.iter() {
+                h = (h ^ xxh64_round(0, v))
+                    .wrapping_mul(PRIME64_1)
+                    .wrapping_add(PRIME64_4);
+            }
+            h
+        } else {
+            self.seed.wrapping_add(PRIME64_5)
+        };
+        h = h.wrapping_add(self.len);
+
+        let tail = &self.block[0..self.offset];
+        let mut words = tail.chunks_exact(8);
+        for word in &mut words {
+            h ^= xxh64_round(0, read64(word, 0));
+            h = h
+                .rotate_left(27)
+                .wrapping_mul(PRIME64_1)
+                .wrapping_add(PRIME64_4);
+        }
+
+        let mut rest = words.remainder();
+        if rest.len() >= 4 {
+            h ^= read32(rest, 0).wrapping_mul(PRIME64_1);
+            h = h
+                .rotate_left(23)
+                .wrapping_mul(PRIME64_2)
+                .wrapping_add(PRIME64_3);
+            rest = &rest[4..];
+        }
+
+        for &byte in rest {
+            h ^= (byte as u64).wrapping_mul(PRIME64_5);
+            h = h.rotate_left(11).wrapping_mul(PRIME64_1);
+        }
+
+        *self = Self::with_seed(self.seed);
+        xxh64_avalanche(h).to_be_bytes()
+    }
+}
+
+fn xxh3_avalanche(mut h: u64) -> u64 {
+    h ^= h >> 37;
+    h = h.wrapping_mul(PRIME_MX1);
+    h ^ (h >> 32)
+}
+
+fn rrmxmx(mut h: u64, len: u64) -> u64 {
+    h ^= h.rotate_left(49) ^ h.rotate_left(24);
+    h = h.wrapping_mul(PRIME_MX2);
+    h ^= (h >> 35).wrapping_add(len);
+    h = h.wrapping_mul(PRIME_MX2);
+    h ^ (h >> 28)
+}
+
+fn mul128(x: u64, y: u64) -> (u64, u64) {
+    let product = x as u128 * y as u128;
+    (product as u64, (product >> 64) as u64)
+}
+
+fn mul128_fold64(x: u64, y: u64) -> u64 {
+    let (low, high) = mul128(x, y);
+    low ^ high
+}
+
+fn mix16(bytes: &[u8], offset: usize, secret_offset: usize, seed: u64) -> u64 {
+    mul128_fold64(
+        read64(bytes, offset) ^ read64(&SECRET, secret_offset).wrapping_add(seed),
+        read64(bytes, offset + 8) ^ read64(&SECRET, secret_offset + 8).wrapping_sub(seed),
+    )
+}
+
+fn mix32(
+    (low, high): (u64, u64),
+    bytes: &[u8],
+    (offset1, offset2): (usize, usize),
+    secret_offset: usize,
+    seed: u64,
+) -> (u64, u64) {
+    let low = low.wrapping_add(mix16(bytes, offset1, secret_offset, seed))
+        ^ read64(bytes, offset2).wrapping_add(read64(bytes, offset2 + 8));
+    let high = high.wrapping_add(mix16(bytes, offset2, secret_offset + 16, seed))
+        ^ read64(bytes, offset1).wrapping_add(read64(bytes, offset1 + 8));
+    (low, high)
+}
+
+fn xxh3_64_short(bytes: &[u8], seed: u64) -> u64 {
+    let len = bytes.len();
+
+    if len == 0 {
+        xxh64_avalanche(seed ^ read64(&SECRET, 56) ^ read64(&SECRET, 64))
+    } else if len <= 3 {
+        let combined = (bytes[0] as u64) << 16
+            | (bytes[len >> 1] as u64) << 24
+            | bytes[len - 1] as u64
+            | (len as u64) << 8;
+        let flip = (read32(&SECRET, 0) ^ read32(&SECRET, 4)).wrapping_add(seed);
+        xxh64_avalanche(combined ^ flip)
+    } else if len <= 8 {
+        let seed = seed ^ ((seed as u32).swap_bytes() as u64) << 32;
+        let input = read32(bytes, len - 4).wrapping_add(read32(bytes, 0) << 32);
+        let flip = (read64(&SECRET, 8) ^ read64(&SECRET, 16)).wrapping_sub(seed);
+        rrmxmx(input ^ flip, len as u64)
+    } else if len <= 16 {
+        let low = read64(bytes, 0) ^ (read64(&SECRET, 24) ^ read64(&SECRET, 32)).wrapping_add(seed);
+        let high =
+            read64(bytes, len - 8) ^ (read64(&SECRET, 40) ^ read64(&SECRET, 48)).wrapping_sub(seed);
+        xxh3_avalanche(
+            (len as u64)
+                .wrapping_add(low.swap_bytes())
+                .wrapping_add(high)
+                .wrapping_add(mul128_fold64(low, high)),
+        )
+    } else if len <= 128 {
+        let mut h = (len as u64).wrapping_mul(PRIME64_1);
+        for i in (0..(len - 1) / 32 + 1).rev() {
+            h = h.wrapping_add(mix16(bytes, 16 * i, 32 * i, seed));
+            h = h.wrapping_add(mix16(bytes, len - 16 * (i + 1), 32 * i + 16, seed));
+        }
+        xxh3_avalanche(h)
+    } else {
+        let mut h = (len as u64).wrapping_mul(PRIME64_1);
+        for i in 0..8 {
+            h = h.wrapping_add(mix16(bytes, 16 * i, 16 * i, seed));
+        }
+        h = xxh3_avalanche(h);
+        for i in 8..len / 16 {
+            h = h.wrapping_add(mix16(bytes, 16 * i, 16 * (i - 8) + 3, seed));
+        }
+        h = h.wrapping_add(mix16(bytes, len - 16, 136 - 17, seed));
+        xxh3_avalanche(h)
+    }
+}
+
+fn xxh3_128_short(bytes: &[u8], seed: u64) -> (u64, u64) {
+    let len = bytes.len();
+
+    if len == 0 {
+        (
+            xxh64_avalanche(seed ^ read64(&SECRET, 64) ^ read64(&SECRET, 72)),
+            xxh64_avalanche(seed ^ read64(&SECRET, 80) ^ read64(&SECRET, 88)),
+        )
+    } else if len <= 3 {
+        let low = (bytes[0] as u32) << 16
+            | (bytes[len >> 1] as u32) << 24
+            | bytes[len - 1] as u32
+            | (len as u32) << 8;
+        let high = low.swap_bytes().rotate_left(13);
+        let flip_low = (read32(&SECRET, 0) ^ read32(&SECRET, 4)).wrapping_add(seed);
+        let flip_high = (read32(&SECRET, 8) ^ read32(&SECRET, 12)).wrapping_sub(seed);
+        (
+            xxh64_avalanche(low as u64 ^ flip_low),
+            xxh64_avalanche(high as u64 ^ flip_high),
+        )
+    } else if len <= 8 {
+        let seed = seed ^ ((seed as u32).swap_bytes() as u64) << 32;
+        let input = read32(bytes, 0).wrapping_add(read32(bytes, len - 4) << 32);
+        let flip = (read64(&SECRET, 16) ^ read64(&SECRET, 24)).wrapping_add(seed);
+        let (mut low, mut high) = mul128(input ^ flip, PRIME64_1.wrapping_add((len as u64) << 2));
+        high = high.wrapping_add(low << 1);
+        low ^= high >> 3;
+        low ^= low >> 35;
+        low = low.wrapping_mul(PRIME_MX2);
+        low ^= low >> 28;
+        (low, xxh3_avalanche(high))
+    } else if len <= 16 {
+        let flip_low = (read64(&SECRET, 32) ^ read64(&SECRET, 40)).wrapping_sub(seed);
+        let flip_high = (read64(&SECRET, 48) ^ read64(&SECRET, 56)).wrapping_add(seed);
+        let input_low = read64(bytes, 0);
+        let input_high = read64(bytes, len - 8) ^ flip_high;
+        let (mut low, mut high) = mul128(input_low ^ read64(bytes, len - 8) ^ flip_low, PRIME64_1);
+        low = low.wrapping_add((len as u64 - 1) << 54);
+        high = high.wrapping_add(
+            input_high.wrapping_add((input_high & 0xffffffff).wrapping_mul(PRIME32_2 - 1)),
+        );
+        low ^= high.swap_bytes();
+        let (result_low, result_high) = mul128(low, PRIME64_2);
+        (
+            xxh3_avalanche(result_low),
+            xxh3_avalanche(result_high.wrapping_add(high.wrapping_mul(PRIME64_2))),
+        )
+    } else {
+        let mut accumulator = ((len as u64).wrapping_mul(PRIME64_1), 0);
+        if len <= 128 {
+            for i in (0..(len - 1) / 32 + 1).rev() {
+                accumulator = mix32(
+                    accumulator,
+                    bytes,
+                    (16 * i, len - 16 * (i + 1)),
+                    32 * i,
+                    seed,
+                );
+            }
+        } else {
+            for i in 0..4 {
+                accumulator = mix32(accumulator, bytes, (32 * i, 32 * i + 16), 32 * i, seed);
+            }
+            accumulator = (xxh3_avalanche(accumulator.0), xxh3_avalanche(accumulator.1));
+            for i in 4..len / 32 {
+                accumulator = mix32(
+                    accumulator,
+                    bytes,
+                    (32 * i, 32 * i + 16),
+                    32 * (i - 4) + 3,
+                    seed,
+                );
+            }
+            accumulator = mix32(
+                accumulator,
+                bytes,
+                (len - 16, len - 32),
+                136 - 17 - 16,
+                0u64.wrapping_sub(seed),
+            );
+        }
+
+        let (low, high) = accumulator;
+        (
+            xxh3_avalanche(low.wrapping_add(high)),
+            0u64.wrapping_sub(xxh3_avalanche(
+                low.wrapping_mul(PRIME64_1)
+                    .wrapping_add(high.wrapping_mul(PRIME64_4))
+                    .wrapping_add((len as u64).wrapping_sub(seed).wrapping_mul(PRIME64_2)),
+            )),
+        )
+    }
+}
+
+#[derive(Clone)]
+pub struct XXH3<const LEN: usize> {
+    seed: u64,
+    secret: [u8; 192],
+    accumulators: [u64; 8],
+    block: [u8; XXH3_BLOCK_BYTES],
+    offset: usize,
+    len: u64,
+}
+
+impl<const LEN: usize> XXH3<LEN> {
+    pub fn with_seed(seed: u64) -> Self {
+        assert!(LEN == 8 || LEN == 16);
+
+        let mut secret = SECRET;
+        for (i, word) in secret.chunks_mut(8).enumerate() {
+            let value = if i % 2 == 0 {
+                read64(word, 0).wrapping_add(seed)
+            } else {
+                read64(word, 0).wrapping_sub(seed)
+            };
+            word.copy_from_slice(&value.to_le_bytes());
+        }
+
+        Self {
+            seed,
+            secret,
+            accumulators: [
+                PRIME32_3, PRIME64_1, PRIME64_2, PRIME64_3, PRIME64_4, PRIME32_2, PRIME64_5,
+                PRIME32_1,
+            ],
+            block: [0u8; XXH3_BLOCK_BYTES],
+            offset: 0,
+            len: 0,
+        }
+    }
+
+    fn accumulate(&self, accumulators: &mut [u64; 8], stripe: &[u8], secret_offset: usize) {
+        for i in 0..8 {
+            let value = read64(stripe, 8 * i);
+            let key = value ^ read64(&self.secret, secret_offset + 8 * i);
+            accumulators[i ^ 1] = accumulators[i ^ 1].wrapping_add(value);
+            accumulators[i] =
+                accumulators[i].wrapping_add((key & 0xffffffff).wrapping_mul(key >> 32));
+        }
+    }
+
+    fn compress(&mut self) {
+        let mut accumulators = self.accumulators;
+        for (i, stripe) in self.block.chunks(STRIPE_BYTES).enumerate() {
+            self.accumulate(&mut accumulators, stripe, 8 * i);
+        }
+
+        for (i, accumulator) in accumulators.iter_mut().enumerate() {
+            let key = read64(&self.secret, 192 - STRIPE_BYTES + 8 * i);
+            *accumulator = (*accumulator ^ (*accumulator >> 47) ^ key).wrapping_mul(PRIME32_1);
+        }
+        self.accumulators = accumulators;
+    }
+
+    fn merge(&self, accumulators: &[u64; 8], secret_offset: usize, initial: u64) -> u64 {
+        let h = (0..4).fold(initial, |h, i| {
+            h.wrapping_add(mul128_fold64(
+                accumulators[2 * i] ^ read64(&self.secret, secret_offset + 16 * i),
+                accumulators[2 * i + 1] ^ read64(&self.secret, secret_offset + 16 * i + 8),
+            ))
+        });
+        xxh3_avalanche(h)
+    }
+
+    fn finish(&self) -> (u64, u64) {
+        let len = self.offset;
+        let mut accumulators = self.accumulators;
+
+        for i in 0..(len - 1) / STRIPE_BYTES {
+            let stripe = &self.block[STRIPE_BYTES * i..STRIPE_BYTES * (i + 1)];
+            self.accumulate(&mut accumulators, stripe, 8 * i);
+        }
+
+        let mut last = [0u8; STRIPE_BYTES];
+        if len >= STRIPE_BYTES {
+            last.copy_from_slice(&self.block[len - STRIPE_BYTES..len]);
+        } else {
+            let carried = STRIPE_BYTES - len;
+            last[0..carried].copy_from_slice(&self.block[XXH3_BLOCK_BYTES - carried..]);
+            last[carried..].copy_from_slice(&self.block[0..len]);
+        }
+        self.accumulate(&mut accumulators, &last, 192 - STRIPE_BYTES - 7);
+
+        (
+            self.merge(&accumulators, 11, self.len.wrapping_mul(PRIME64_1)),
+            self.merge(
+                &accumulators,
+                192 - STRIPE_BYTES - 11,
+                !self.len.wrapping_mul(PRIME64_2),
+            ),
+        )
+    }
+}
+
+impl<const LEN: usize> Digest<[u8; LEN]> for XXH3<LEN> {
+    const BLOCK_SIZE: usize = STRIPE_BYTES;
+    const OUTPUT_SIZE: usize = LEN;
+
+    fn new() -> Self {
+        Self::with_seed(0)
+    }
+
+    fn hash(bytes: impl AsRef<[u8]>) -> [u8; LEN] {
+        Self::new().chain(bytes).finalize()
+    }
+
+    fn update(&mut self, bytes: impl AsRef<[u8]>) {
+        let mut bytes = bytes.as_ref();
+        self.len += bytes.len() as u64;
+
+        while !bytes.is_empty() {
+            if self.offset == XXH3_BLOCK_BYTES {
+                self.compress();
+                self.offset = 0;
+            }
+
+            let len = (XXH3_BLOCK_BYTES - self.offset).min(bytes.len());
+            self.block[self.offset..self.offset + len].copy_from_slice(&bytes[0..len]);
+            self.offset += len;
+            bytes = &bytes[len..];
+        }
+    }
+
+    fn chain(mut self, bytes: impl AsRef<[u8]>) -> Self {
+        self.update(bytes);
+        self
+    }
+
+    fn finalize(mut self) -> [u8; LEN] {
+        self.finalize_reset()
+    }
+
+    fn finalize_reset(&mut self) -> [u8; LEN] {
+        let (low, high) = if self.len > 240 {
+            self.finish()
+        } else if LEN == 8 {
+            (xxh3_64_short(&self.block[0..self.offset], self.seed), 0)
+        } else {
+            xxh3_128_short(&self.block[0..self.offset], self.seed)
+        };
+
+        let mut bytes = [0u8; LEN];
+        if LEN == 8 {
+            bytes.copy_from_slice(&low.to_be_bytes());
+        } else {
+            bytes[0..8].copy_from_slice(&high.to_be_bytes());
+            bytes[8..16].copy_from_slice(&low.to_be_bytes());
+        }
+
+        *self = Self::with_seed(self.seed);
+        bytes
+    }
+}
+
+pub type XXH3x64Digest = XXH3<8>;
+pub type XXH3x128Digest = XXH3<16>;